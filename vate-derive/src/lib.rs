@@ -1,6 +1,7 @@
 use proc_macro::TokenStream;
 
 mod path;
+mod regex;
 mod validate;
 
 #[proc_macro_derive(Validate, attributes(vate))]
@@ -18,3 +19,11 @@ pub fn path(input: TokenStream) -> TokenStream {
         .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
+
+#[proc_macro]
+pub fn regex(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::LitStr);
+    regex::expand_regex(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -30,11 +30,24 @@ fn parse_expr(expr: &syn::Expr, accessors: &mut Vec<TokenStream2>) -> syn::Resul
                     let key = str.value();
                     accessors.push(quote!(::vate::Accessor::Key(#key.to_string())));
                 }
-                syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(int), .. }) => {
+                syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(int), .. })
+                    if matches!(int.suffix(), "" | "usize") =>
+                {
                     let index = int.base10_parse::<usize>()?;
                     accessors.push(quote!(::vate::Accessor::Index(#index)));
                 }
-                _ => return Err(syn::Error::new_spanned(index, "Expected `usize` to generate `Accessor::Index` or `&'static str` to generate to generate `Accessor::Key`")),
+                syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(int), .. }) => {
+                    let key = int.base10_parse::<i128>()?;
+                    accessors.push(quote!(::vate::Accessor::IntegerKey(#key)));
+                }
+                syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => {
+                    let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(int), .. }) = &**expr else {
+                        return Err(syn::Error::new_spanned(index, "Expected a suffixed integer to generate `Accessor::IntegerKey`"));
+                    };
+                    let key = -int.base10_parse::<i128>()?;
+                    accessors.push(quote!(::vate::Accessor::IntegerKey(#key)));
+                }
+                _ => return Err(syn::Error::new_spanned(index, "Expected `usize` to generate `Accessor::Index`, a suffixed integer such as `5u64` to generate `Accessor::IntegerKey`, or `&'static str` to generate to generate `Accessor::Key`")),
             }
         }
         _ => return Err(syn::Error::new_spanned(expr, "Unsupported expression")),
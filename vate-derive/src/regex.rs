@@ -0,0 +1,15 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+pub fn expand_regex(input: syn::LitStr) -> syn::Result<TokenStream2> {
+    let pattern = input.value();
+    if let Err(error) = regex::Regex::new(&pattern) {
+        return Err(syn::Error::new_spanned(input, error));
+    }
+    Ok(quote! {
+        {
+            static REGEX: ::std::sync::OnceLock<::vate::extras::Regex> = ::std::sync::OnceLock::new();
+            REGEX.get_or_init(|| ::vate::extras::Regex::new(#input).unwrap())
+        }
+    })
+}
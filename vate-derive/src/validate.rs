@@ -1,4 +1,4 @@
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Delimiter, TokenStream as TokenStream2, TokenTree};
 use quote::quote;
 use syn::punctuated::Punctuated;
 
@@ -45,17 +45,51 @@ pub fn expand_derive_validate_struct(
     }
 
     let mut body = Vec::new();
+    let mut body_at = Vec::new();
+    let mut names = Vec::new();
 
     for (index, field) in data.fields.into_iter().enumerate() {
         let item_ident = field.ident.map_or(quote!(#index), |ident| quote!(#ident));
+        names.push(quote!(stringify!(#item_ident)));
         for attr in field.attrs.iter() {
             if !attr.path().is_ident("vate") {
                 continue;
             }
-            let tokens = &attr.meta.require_list()?.tokens;
+            let tokens = attr.meta.require_list()?.tokens.clone();
+            let is_nested = matches!(
+                tokens.clone().into_iter().collect::<Vec<_>>().as_slice(),
+                [TokenTree::Ident(ident)] if ident == "Nested"
+            );
+            let tokens = expand_each(tokens);
             let code = quote! {
                 ::vate::Bundle!(#tokens).run::<C>(::vate::Accessor::Field(stringify!(#item_ident)), &self.#item_ident, data, parent_report)?;
             };
+            // Sub-paths of a nested struct are dispatched to its `validate_at`. Other
+            // validators can't validate part of a field, so the entire field is validated.
+            let code_at = if is_nested {
+                quote! {
+                    ::vate::NestedAt(&sub_paths).run::<C>(::vate::Accessor::Field(stringify!(#item_ident)), &self.#item_ident, data, parent_report)?;
+                }
+            } else {
+                code.clone()
+            };
+            body_at.push(quote! {
+                {
+                    let field_paths = paths
+                        .iter()
+                        .filter(|path| path[0] == ::vate::Accessor::Field(stringify!(#item_ident)));
+                    if field_paths.clone().any(|path| path.len() == 1) {
+                        #code
+                    } else {
+                        let sub_paths = field_paths
+                            .map(|path| &path[1..])
+                            .collect::<::std::vec::Vec<_>>();
+                        if !sub_paths.is_empty() {
+                            #code_at
+                        }
+                    }
+                }
+            });
             body.push(code);
         }
     }
@@ -72,9 +106,65 @@ pub fn expand_derive_validate_struct(
                 parent_report: &mut ::vate::Report<Self::Error>,
             ) -> Result<(), ::vate::Exit<Self::Error>> {
                 use ::vate::Validator;
+                let _span = ::vate::__private::validate_span(stringify!(#ident), parent_report);
                 #(#body)*
                 Ok(())
             }
+
+            fn validate_at<C: ::vate::Collector<Self::Error>>(
+                &self,
+                paths: &[&[::vate::Accessor]],
+                data: &Self::Data,
+                parent_report: &mut ::vate::Report<Self::Error>,
+            ) -> Result<(), ::vate::Exit<Self::Error>> {
+                use ::vate::Validator;
+                if paths.iter().any(|path| path.is_empty()) {
+                    return self.validate::<C>(data, parent_report);
+                }
+                let _span = ::vate::__private::validate_span(stringify!(#ident), parent_report);
+                ::vate::__private::validate_unknown_fields::<C, _>(
+                    stringify!(#ident),
+                    &[#(#names),*],
+                    paths,
+                    parent_report,
+                )?;
+                #(#body_at)*
+                Ok(())
+            }
         }
     })
 }
+
+/// Expand the `each(...)` and `each_value(...)` validators, which run validators on the elements
+/// of a collection or the values of a map, into `CollectionIterate(IteratorIndexed(...))` and
+/// `CollectionIterate(IteratorKeyed(...))`. Other validators are left as they are.
+fn expand_each(tokens: TokenStream2) -> TokenStream2 {
+    let mut validators = vec![Vec::new()];
+    for token in tokens {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => validators.push(Vec::new()),
+            _ => validators.last_mut().unwrap().push(token),
+        }
+    }
+
+    let validators = validators
+        .into_iter()
+        .filter(|validator| !validator.is_empty())
+        .map(|validator| match validator.as_slice() {
+            [TokenTree::Ident(ident), TokenTree::Group(group)]
+                if group.delimiter() == Delimiter::Parenthesis
+                    && (ident == "each" || ident == "each_value") =>
+            {
+                let inner = expand_each(group.stream());
+                let iterator = if ident == "each" {
+                    quote!(IteratorIndexed)
+                } else {
+                    quote!(IteratorKeyed)
+                };
+                quote!(::vate::CollectionIterate(::vate::#iterator(::vate::Bundle!(#inner))))
+            }
+            _ => validator.into_iter().collect(),
+        });
+
+    quote!(#(#validators),*)
+}
@@ -0,0 +1,129 @@
+use ::async_graphql::{
+    indexmap::IndexMap, CustomValidator, Error, ErrorExtensions, InputType, InputValueError, Name,
+    Value,
+};
+
+use crate::{reports::flatten, Accessor, Exit, InvalidsAndErrors, Report, Validate};
+
+/// A custom validator that validates an argument or input object field with vate,
+/// passing the contained data to validators. If the target is not valid, the error
+/// has a `validation` extension listing the invalid and erroneous targets.
+pub struct InputValidator<D>(pub D);
+
+impl<T, D> CustomValidator<T> for InputValidator<D>
+where
+    T: InputType + Validate<Data = D>,
+{
+    fn check(&self, value: &T) -> Result<(), InputValueError<T>> {
+        let Self(data) = self;
+
+        let mut report = Report::new(Accessor::Root("input"));
+        if let Err(Exit::WithError(error)) = value.validate::<InvalidsAndErrors>(data, &mut report)
+        {
+            report.set_error(error);
+        }
+
+        if report.is_valid() {
+            Ok(())
+        } else {
+            Err(InputValueError::custom(message(&report))
+                .with_extension("validation", extension(&report)))
+        }
+    }
+}
+
+/// Convert a report into a GraphQL error, for resolvers that validate their inputs manually.
+/// The error has a `validation` extension listing the invalid and erroneous targets.
+pub fn error<E>(report: &Report<E>) -> Error {
+    Error::new(message(report)).extend_with(|_, extensions| {
+        extensions.set("validation", extension(report));
+    })
+}
+
+/// Create the message of an error from the messages of the report.
+fn message<E>(report: &Report<E>) -> String {
+    let summary = crate::MapReport::from(report).summary();
+    format!("invalid input: {summary}")
+}
+
+/// Create the `validation` extension of an error. Each invalid and erroneous report with a
+/// message becomes an object with a `path`, a `code` of either `invalid` or `error`, and
+/// a `message`.
+fn extension<E>(report: &Report<E>) -> Value {
+    let items = flatten(report)
+        .into_iter()
+        .filter(|(_, report)| !report.is_valid() && !report.get_message().is_empty())
+        .map(|(path, report)| {
+            let path = path
+                .into_iter()
+                .map(|accessor| match accessor {
+                    Accessor::Root(field) | Accessor::Field(field) => Value::from(*field),
+                    Accessor::Index(index) => Value::from(*index as u64),
+                    Accessor::Key(key) => Value::from(key.as_str()),
+                    Accessor::IntegerKey(key) => i64::try_from(*key)
+                        .map_or_else(|_| Value::from(key.to_string()), Value::from),
+                })
+                .collect();
+            let code = if report.is_error() {
+                "error"
+            } else {
+                "invalid"
+            };
+
+            let mut item = IndexMap::new();
+            item.insert(Name::new("path"), Value::List(path));
+            item.insert(Name::new("code"), Value::from(code));
+            item.insert(
+                Name::new("message"),
+                Value::from(report.get_message().as_str()),
+            );
+            Value::Object(item)
+        })
+        .collect();
+    Value::List(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use async_graphql::{
+        EmptyMutation, EmptySubscription, InputObject, Object, Request, Schema, Value,
+    };
+    use vate::{async_graphql::InputValidator, StringAlphabetic, Validate};
+
+    #[derive(InputObject, Validate)]
+    struct Example {
+        #[vate(StringAlphabetic)]
+        a: String,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn echo(
+            &self,
+            #[graphql(validator(custom = "InputValidator(())"))] example: Example,
+        ) -> String {
+            example.a
+        }
+    }
+
+    #[tokio::test]
+    async fn input_validator() {
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+        let response = schema
+            .execute(Request::new(r#"{ echo(example: { a: "a" }) }"#))
+            .await;
+        assert!(response.errors.is_empty());
+
+        let response = schema
+            .execute(Request::new(r#"{ echo(example: { a: "0" }) }"#))
+            .await;
+        let extensions = response.errors[0].extensions.as_ref().unwrap();
+        let Some(Value::List(items)) = extensions.get("validation") else {
+            panic!("expected a validation extension");
+        };
+        assert_eq!(items.len(), 1);
+    }
+}
@@ -0,0 +1,147 @@
+use ::axum::{
+    extract::{
+        rejection::{JsonRejection, QueryRejection},
+        FromRef, FromRequest, FromRequestParts, Json, Query, Request,
+    },
+    http::{header, request::Parts, StatusCode},
+    response::{IntoResponse, Response},
+};
+use serde::de::DeserializeOwned;
+
+use crate::{Accessor, InvalidsAndErrors, ProblemDetailsReport, Report, Valid, Validate};
+
+/// Extracts a JSON request body and validates it.
+/// The data passed to validators is extracted from the router state with `FromRef`.
+/// If the body is not valid, the request is rejected with a `422 Unprocessable Content`
+/// problem details response.
+pub struct ValidatedJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    T::Data: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = ValidationRejection<JsonRejection>;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(target) = Json::<T>::from_request(request, state)
+            .await
+            .map_err(ValidationRejection::Extract)?;
+        validate(target, Accessor::Root("body"), state).map(Self)
+    }
+}
+
+/// Extracts a query string and validates it.
+/// The data passed to validators is extracted from the router state with `FromRef`.
+/// If the query string is not valid, the request is rejected with a `422 Unprocessable Content`
+/// problem details response.
+pub struct ValidatedQuery<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for ValidatedQuery<T>
+where
+    T: DeserializeOwned + Validate,
+    T::Data: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = ValidationRejection<QueryRejection>;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(target) = Query::<T>::from_request_parts(parts, state)
+            .await
+            .map_err(ValidationRejection::Extract)?;
+        validate(target, Accessor::Root("query"), state).map(Self)
+    }
+}
+
+/// Validate an extracted target.
+fn validate<T, S, R>(target: T, accessor: Accessor, state: &S) -> Result<T, ValidationRejection<R>>
+where
+    T: Validate,
+    T::Data: FromRef<S>,
+{
+    let data = T::Data::from_ref(state);
+    let mut report = Report::new(accessor);
+    Valid::new::<InvalidsAndErrors>(target, &data, &mut report)
+        .map(Valid::into_inner)
+        .map_err(|_| ValidationRejection::Invalid(ProblemDetailsReport::from(&report)))
+}
+
+/// The rejection of the validated extractors.
+#[derive(Debug)]
+pub enum ValidationRejection<R> {
+    /// The target could not be extracted.
+    Extract(R),
+    /// The target was extracted, but is not valid.
+    Invalid(ProblemDetailsReport),
+}
+
+impl<R: IntoResponse> IntoResponse for ValidationRejection<R> {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Extract(rejection) => rejection.into_response(),
+            Self::Invalid(problem_details) => {
+                let status = StatusCode::from_u16(problem_details.status)
+                    .unwrap_or(StatusCode::UNPROCESSABLE_ENTITY);
+                (
+                    status,
+                    [(header::CONTENT_TYPE, ProblemDetailsReport::CONTENT_TYPE)],
+                    Json(problem_details),
+                )
+                    .into_response()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        extract::{FromRequest, Request},
+        http::{header, StatusCode},
+        response::IntoResponse,
+    };
+    use serde::Deserialize;
+    use vate::{axum::ValidatedJson, StringAlphabetic, Validate};
+
+    #[derive(Deserialize, Validate)]
+    struct Example {
+        #[vate(StringAlphabetic)]
+        a: String,
+    }
+
+    fn request(body: &'static str) -> Request {
+        Request::builder()
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn validated_json() {
+        let ValidatedJson(example) =
+            ValidatedJson::<Example>::from_request(request(r#"{"a":"a"}"#), &())
+                .await
+                .ok()
+                .unwrap();
+
+        assert_eq!(example.a, "a");
+    }
+
+    #[tokio::test]
+    async fn validated_json_invalid() {
+        let rejection = ValidatedJson::<Example>::from_request(request(r#"{"a":"0"}"#), &())
+            .await
+            .err()
+            .unwrap();
+
+        let response = rejection.into_response();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "application/problem+json"
+        );
+    }
+}
@@ -1,10 +1,11 @@
 use crate::{Collector, Exit, Report};
 
 /// Collects all invalid reports and error reports.
+#[derive(Clone, Copy, Debug, Default)]
 pub struct InvalidsAndErrors;
 
 impl<E> Collector<E> for InvalidsAndErrors {
-    fn apply(parent: &mut Report<E>, child: Report<E>) -> Result<(), Exit<E>> {
+    fn collect(parent: &mut Report<E>, child: Report<E>) -> Result<(), Exit<E>> {
         match child.get_validity() {
             Ok(true) => {}
             Ok(false) => {
@@ -27,10 +28,11 @@ impl<E> Collector<E> for InvalidsAndErrors {
 /// Collects only the first invalid report and all error reports that were
 /// encountered prior to the first invalid report (if any). Exits gracefully
 /// when the first invalid report is encountered.
+#[derive(Clone, Copy, Debug, Default)]
 pub struct FirstInvalidAndPrecedingErrors;
 
 impl<E> Collector<E> for FirstInvalidAndPrecedingErrors {
-    fn apply(parent: &mut Report<E>, child: Report<E>) -> Result<(), Exit<E>> {
+    fn collect(parent: &mut Report<E>, child: Report<E>) -> Result<(), Exit<E>> {
         match child.get_validity() {
             Ok(true) => {}
             Ok(false) => {
@@ -51,11 +53,44 @@ impl<E> Collector<E> for FirstInvalidAndPrecedingErrors {
     }
 }
 
+/// Collects only the first invalid report of each field, key, or index, and all error
+/// reports. Unlike `FirstInvalidAndPrecedingErrors`, this doesn't exit, so every target is
+/// validated, but each invalid target only has the message of its first failed validator.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FirstPerField;
+
+impl<E> Collector<E> for FirstPerField {
+    fn collect(parent: &mut Report<E>, child: Report<E>) -> Result<(), Exit<E>> {
+        match child.get_validity() {
+            Ok(true) => {}
+            Ok(false) => {
+                // If the parent validity is valid, set it to invalid, since the child is invalid.
+                // If the parent validity is an error, this collector will respect that error and not
+                // overwrite it.
+                if parent.is_valid() {
+                    parent.set_invalid();
+                }
+                let is_first = !parent
+                    .get_child(child.get_accessor())
+                    .is_some_and(Report::is_invalid);
+                if is_first {
+                    parent.push_child(child);
+                }
+            }
+            Err(_) => {
+                parent.push_child(child);
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Collects everything.
+#[derive(Clone, Copy, Debug, Default)]
 pub struct Everything;
 
 impl<E> Collector<E> for Everything {
-    fn apply(parent: &mut Report<E>, child: Report<E>) -> Result<(), Exit<E>> {
+    fn collect(parent: &mut Report<E>, child: Report<E>) -> Result<(), Exit<E>> {
         if child.is_invalid() {
             // If the parent validity is valid, set it to invalid, since the child is invalid.
             // If the parent validity is an error, this collector will respect that error and not
@@ -68,3 +103,153 @@ impl<E> Collector<E> for Everything {
         Ok(())
     }
 }
+
+/// Collects only error reports, along with the reports leading to them. Invalid reports
+/// still set the parent validity to invalid, but are not stored.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ErrorsOnly;
+
+impl<E> Collector<E> for ErrorsOnly {
+    fn collect(parent: &mut Report<E>, child: Report<E>) -> Result<(), Exit<E>> {
+        // If the parent validity is an error, this collector will respect that error and not
+        // overwrite it.
+        if child.is_invalid() && parent.is_valid() {
+            parent.set_invalid();
+        }
+        if child.iter().any(Report::is_error) {
+            parent.push_child(child);
+        }
+        Ok(())
+    }
+}
+
+/// Collects only the validity of child reports, without storing the child reports.
+/// Useful when only a valid or invalid answer is needed. An erroneous child report sets
+/// the parent validity to its error, since the child report itself is not stored.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ValidityOnly;
+
+impl<E> Collector<E> for ValidityOnly {
+    fn collect(parent: &mut Report<E>, child: Report<E>) -> Result<(), Exit<E>> {
+        match child.validity {
+            Ok(true) => {}
+            Ok(false) => {
+                // If the parent validity is an error, this collector will respect that error and not
+                // overwrite it.
+                if parent.is_valid() {
+                    parent.set_invalid();
+                }
+            }
+            Err(error) => {
+                if !parent.is_error() {
+                    parent.set_error(error);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vate::{
+        path, Accessor, Collector, ErrorsOnly, Exit, FirstPerField, Nested, Report,
+        StringAlphabetic, StringLengthRange, Validate, Validator, ValidityOnly,
+    };
+
+    #[test]
+    fn validity_only() {
+        #[derive(Validate)]
+        struct Example1 {
+            #[vate(Nested)]
+            example2: Example2,
+        }
+
+        #[derive(Validate)]
+        struct Example2 {
+            #[vate(StringAlphabetic)]
+            a: String,
+        }
+
+        let example1 = Example1 {
+            example2: Example2 {
+                a: String::from("0"),
+            },
+        };
+
+        let mut report = Report::new(Accessor::Root("example1"));
+        let _ = example1.validate::<ValidityOnly>(&(), &mut report);
+
+        assert!(report.is_invalid());
+        assert_eq!(report.children().count(), 0);
+    }
+
+    #[test]
+    fn errors_only() {
+        struct Unavailable;
+
+        impl<T, D> Validator<T, D, ()> for Unavailable {
+            fn run<C: Collector<()>>(
+                &self,
+                accessor: Accessor,
+                _target: &T,
+                _data: &D,
+                parent_report: &mut Report<()>,
+            ) -> Result<(), Exit<()>> {
+                let mut child_report = Report::new(accessor);
+                child_report.set_error(());
+                C::apply(parent_report, child_report)
+            }
+        }
+
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringAlphabetic)]
+            a: String,
+            #[vate(Unavailable)]
+            b: String,
+        }
+
+        let example = Example {
+            a: String::from("0"),
+            b: String::from("b"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<ErrorsOnly>(&(), &mut report);
+
+        assert!(report.is_invalid());
+        assert!(report.get_at_path(path!(example.a)).is_none());
+        assert!(report.get_at_path(path!(example.b)).unwrap().is_error());
+    }
+
+    #[test]
+    fn first_per_field() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringAlphabetic, StringLengthRange::Chars { min: 4, max: 8 })]
+            a: String,
+            #[vate(StringLengthRange::Chars { min: 4, max: 8 })]
+            b: String,
+            #[vate(StringAlphabetic)]
+            c: String,
+        }
+
+        let example = Example {
+            a: String::from("0"),
+            b: String::from("b"),
+            c: String::from("c"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<FirstPerField>(&(), &mut report);
+
+        assert!(report.is_invalid());
+        assert_eq!(
+            report.get_at_path(path!(example.a)).unwrap().get_message(),
+            "contains non-alphabetic characters"
+        );
+        assert!(report.is_invalid_at_path(path!(example.b)).unwrap());
+        assert!(report.get_at_path(path!(example.c)).is_none());
+    }
+}
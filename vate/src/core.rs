@@ -6,6 +6,8 @@ use std::{
     ops::Deref,
 };
 
+use crate::ValidityOnly;
+
 /// Allows the implementor to be validated.
 pub trait Validate {
     /// Custom data type passed to validators.
@@ -18,6 +20,61 @@ pub trait Validate {
         data: &Self::Data,
         parent_report: &mut Report<Self::Error>,
     ) -> Result<(), Exit<Self::Error>>;
+    /// Validate only the specified paths of the target, such as the fields changed by a
+    /// partial update. Paths are relative to the target, so they start with a field, such as
+    /// `[Accessor::Field("address"), Accessor::Field("city")]`, and an empty path specifies
+    /// the entire target.
+    ///
+    /// Derived implementations run the validators of the fields the paths start with. The
+    /// rest of the paths are passed to the `validate_at` of `#[vate(Nested)]` fields, and the
+    /// entire field is validated otherwise. A path that doesn't start with a field of the
+    /// target adds an invalid report with that accessor.
+    ///
+    /// The default implementation can't tell which validators belong to which fields, so it
+    /// validates the entire target.
+    fn validate_at<C: Collector<Self::Error>>(
+        &self,
+        paths: &[&[Accessor]],
+        data: &Self::Data,
+        parent_report: &mut Report<Self::Error>,
+    ) -> Result<(), Exit<Self::Error>> {
+        let _ = paths;
+        self.validate::<C>(data, parent_report)
+    }
+}
+
+/// Validate the items of an iterator one at a time, such as rows read from a large file,
+/// without collecting them first. Each item is validated as a child of the parent report
+/// with `Accessor::Index`, and stops early if the collector exits. Only the reports kept
+/// by the collector are held in memory, so collectors such as `InvalidsAndErrors` keep the
+/// report small.
+pub fn validate_iter<I, C>(
+    items: I,
+    data: &<I::Item as Validate>::Data,
+    parent_report: &mut Report<<I::Item as Validate>::Error>,
+) -> Result<(), Exit<<I::Item as Validate>::Error>>
+where
+    I: IntoIterator,
+    I::Item: Validate,
+    C: Collector<<I::Item as Validate>::Error>,
+{
+    items.into_iter().enumerate().try_for_each(|(index, item)| {
+        crate::Nested.run::<C>(Accessor::Index(index), &item, data, parent_report)
+    })
+}
+
+/// Extracts the data of a nested validation from the data of its parent.
+/// Every data type can be extracted from itself, so nested targets that share
+/// the data type of their parent don't need to implement this.
+pub trait FromData<D> {
+    /// Extract the data from the parent data.
+    fn from_data(data: &D) -> &Self;
+}
+
+impl<D> FromData<D> for D {
+    fn from_data(data: &D) -> &Self {
+        data
+    }
 }
 
 /// Defines a validator.
@@ -30,15 +87,94 @@ pub trait Validator<T, D, E> {
         data: &D,
         parent_report: &mut Report<E>,
     ) -> Result<(), Exit<E>>;
+    /// Check if the target passes the validator, without keeping a report.
+    /// Errors are considered failures. Validators can override this with a faster check.
+    fn passes(&self, target: &T, data: &D) -> bool {
+        let mut report = Report::new(Accessor::Root("target"));
+        self.run::<ValidityOnly>(Accessor::Root("target"), target, data, &mut report)
+            .is_ok()
+            && report.is_valid()
+    }
+}
+
+/// Validate a value that isn't a field of a struct, such as a query parameter or a command line
+/// argument, returning its report. The report has the accessor `Accessor::Root` with the given
+/// root, and every report is collected. Data can be passed to the validators with `data = ...`.
+/// If a validator exits with an error, the error is set on the report.
+#[macro_export]
+macro_rules! validate {
+    ($target:expr, root = $root:expr, [$($validators:tt)*]) => {
+        $crate::validate!($target, root = $root, data = &(), [$($validators)*])
+    };
+    ($target:expr, root = $root:expr, data = $data:expr, [$($validators:tt)*]) => {{
+        use $crate::Validator as _;
+
+        let accessor = $crate::Accessor::Root($root);
+        let mut parent_report = $crate::Report::new(accessor.clone());
+        let result = $crate::Bundle!($($validators)*).run::<$crate::Everything>(
+            accessor.clone(),
+            &$target,
+            $data,
+            &mut parent_report,
+        );
+
+        let mut report = parent_report
+            .take_child(&accessor)
+            .unwrap_or_else(|| $crate::Report::new(accessor));
+        if let Err($crate::Exit::WithError(error)) = result {
+            report.set_error(error);
+        }
+        report
+    }};
+}
+
+/// An object safe version of `Validator`, with the collector chosen by the trait instead
+/// of by each run. Every validator implements it, so validators can be boxed, stored in
+/// collections, and selected at runtime.
+pub trait DynValidator<T, D, E, C: Collector<E>> {
+    /// Run the validator with the collector `C`.
+    fn run_dyn(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>>;
+    /// Box the validator.
+    fn boxed<'a>(self) -> BoxedValidator<'a, T, D, E, C>
+    where
+        Self: Sized + 'a,
+    {
+        Box::new(self)
+    }
 }
 
+impl<T, D, E, C, V> DynValidator<T, D, E, C> for V
+where
+    C: Collector<E>,
+    V: Validator<T, D, E>,
+{
+    fn run_dyn(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        self.run::<C>(accessor, target, data, parent_report)
+    }
+}
+
+/// A boxed validator.
+pub type BoxedValidator<'a, T, D, E, C> = Box<dyn DynValidator<T, D, E, C> + 'a>;
+
 /// A validation report.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Report<E> {
     /// The accessor of the report.
     accessor: Accessor,
     /// The validity determined after validating.
-    validity: Result<bool, E>,
+    pub(crate) validity: Result<bool, E>,
     /// The message associated with the report.
     message: String,
     /// The children of this report.
@@ -99,27 +235,113 @@ impl<E> Report<E> {
     pub fn get_message(&self) -> &String {
         &self.message
     }
-    /// Push a child report to this report.
+    /// Push a child report to this report. If a child report with the same accessor was
+    /// already pushed, such as by another validator of the same target, the child reports
+    /// are merged.
     pub fn push_child(&mut self, child: impl Into<ReportHasher<E>>) {
-        self.children.insert(child.into());
+        let ReportHasher(child) = child.into();
+        match self.children.take(&child.accessor) {
+            Some(ReportHasher(mut existing)) => {
+                existing.merge(child);
+                self.children.insert(ReportHasher(existing));
+            }
+            None => {
+                self.children.insert(ReportHasher(child));
+            }
+        }
+    }
+    /// Merge another report into this report, ignoring the accessor of the other report.
+    /// An error validity takes precedence over an invalid validity, which takes precedence
+    /// over a valid validity. Messages are joined, and child reports with the same accessor
+    /// are merged recursively.
+    pub fn merge(&mut self, other: Report<E>) {
+        let Report {
+            validity,
+            message,
+            children,
+            ..
+        } = other;
+
+        match validity {
+            Ok(true) => {}
+            Ok(false) => {
+                if self.is_valid() {
+                    self.set_invalid();
+                }
+            }
+            Err(error) => {
+                if !self.is_error() {
+                    self.set_error(error);
+                }
+            }
+        }
+
+        if self.message.is_empty() {
+            self.message = message;
+        } else if !message.is_empty() && self.message != message {
+            self.message.push_str("; ");
+            self.message.push_str(&message);
+        }
+
+        for child in children {
+            self.push_child(child);
+        }
+    }
+    /// Merge another report into the child report with the given accessor, creating the
+    /// child report if it doesn't exist. This is useful for combining the reports of
+    /// independently validated targets under one report. The validity of the other report
+    /// is merged into this report too, with the same precedence as `Report::merge`.
+    pub fn merge_child(&mut self, accessor: Accessor, mut other: Report<E>)
+    where
+        E: Clone,
+    {
+        let mut parent = Report::new(self.accessor.clone());
+        parent.validity = other.validity.clone();
+        other.accessor = accessor;
+        parent.push_child(other);
+        self.merge(parent);
     }
     /// Get a child report given an accessor.
     pub fn get_child(&self, accessor: &Accessor) -> Option<&Report<E>> {
         self.children.get(accessor).map(|v| &**v)
     }
-    /// Get the validity of a path in the report.
+    /// Remove a child report given an accessor, returning it.
+    pub fn take_child(&mut self, accessor: &Accessor) -> Option<Report<E>> {
+        self.children
+            .take(accessor)
+            .map(|ReportHasher(child)| child)
+    }
+    /// Iterate over the child reports of this report.
+    pub fn children(&self) -> impl Iterator<Item = &Report<E>> {
+        self.children.iter().map(|v| &**v)
+    }
+    /// Iterate over this report and all of its descendant reports, depth-first.
+    pub fn iter(&self) -> impl Iterator<Item = &Report<E>> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let report = stack.pop()?;
+            stack.extend(report.children());
+            Some(report)
+        })
+    }
+    /// Get the nested report at a path in the report.
     /// If the path isn't found, `None` is returned. If the path isn't found,
     /// this does NOT mean the struct does not have this path. It just means it is
     /// not in the report. This can be due to many reasons, such as because nothing on
     /// that path was validated, the validation was skipped, etc.
-    pub fn get_validity_at_path(&self, path: impl AsRef<[Accessor]>) -> Option<&Result<bool, E>> {
+    pub fn get_at_path(&self, path: impl AsRef<[Accessor]>) -> Option<&Report<E>> {
         let (first, rest) = path.as_ref().split_first()?;
         if let Some(next) = rest.first() {
-            self.get_child(next)?.get_validity_at_path(rest)
+            self.get_child(next)?.get_at_path(rest)
         } else {
-            (*first == self.accessor).then_some(&self.validity)
+            (*first == self.accessor).then_some(self)
         }
     }
+    /// Get the validity of a path in the report.
+    /// See `Report::get_at_path` for when `None` is returned.
+    pub fn get_validity_at_path(&self, path: impl AsRef<[Accessor]>) -> Option<&Result<bool, E>> {
+        self.get_at_path(path).map(Report::get_validity)
+    }
     /// Check if the nested report at the path is valid.
     pub fn is_valid_at_path(&self, path: impl AsRef<[Accessor]>) -> Option<bool> {
         let validity = self.get_validity_at_path(path)?;
@@ -135,6 +357,26 @@ impl<E> Report<E> {
         let validity = self.get_validity_at_path(path)?;
         Some(validity.is_err())
     }
+    /// Check if the nested report at the path, or any of its descendants, is invalid.
+    pub fn is_any_invalid_at_path(&self, path: impl AsRef<[Accessor]>) -> Option<bool> {
+        let report = self.get_at_path(path)?;
+        Some(report.iter().any(Report::is_invalid))
+    }
+    /// Check if the nested report at the path, or any of its descendants, is erroneous.
+    pub fn is_any_error_at_path(&self, path: impl AsRef<[Accessor]>) -> Option<bool> {
+        let report = self.get_at_path(path)?;
+        Some(report.iter().any(Report::is_error))
+    }
+    /// Count the reports without children in the nested report at the path.
+    pub fn count_leaves_at_path(&self, path: impl AsRef<[Accessor]>) -> Option<usize> {
+        let report = self.get_at_path(path)?;
+        Some(
+            report
+                .iter()
+                .filter(|report| report.children.is_empty())
+                .count(),
+        )
+    }
     /// A method used by `<Report as Display>::fmt` to stringify the report.
     fn stringify(&self, current_path: Option<Vec<&Accessor>>) -> String {
         let mut stringified = String::new();
@@ -156,16 +398,53 @@ impl<E> Report<E> {
 
         stringified
     }
+    /// Write this report and its descendants as an indented tree, one report per line.
+    /// Child reports are sorted by accessor.
+    fn write_tree(&self, depth: usize, f: &mut Formatter<'_>) -> FmtResult {
+        let validity = match self.validity {
+            Ok(true) => "valid",
+            Ok(false) => "invalid",
+            Err(_) => "error",
+        };
+        write!(
+            f,
+            "{:indent$}{} ({validity})",
+            "",
+            self.accessor,
+            indent = depth * 2
+        )?;
+        if !self.message.is_empty() {
+            write!(f, ": {}", self.message)?;
+        }
+        writeln!(f)?;
+
+        let mut children = self.children().collect::<Vec<_>>();
+        children.sort_by(|a, b| match (&a.accessor, &b.accessor) {
+            (Accessor::Index(a), Accessor::Index(b)) => a.cmp(b),
+            (Accessor::IntegerKey(a), Accessor::IntegerKey(b)) => a.cmp(b),
+            (a, b) => a.to_string().cmp(&b.to_string()),
+        });
+        for child in children {
+            child.write_tree(depth + 1, f)?;
+        }
+        Ok(())
+    }
 }
 
+/// Formats the path and message of every report with a message, one per line.
+/// The alternate flag (`{:#}`) formats the report as an indented tree instead.
 impl<E> Display for Report<E> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "{}", self.stringify(None))
+        if f.alternate() {
+            self.write_tree(0, f)
+        } else {
+            write!(f, "{}", self.stringify(None))
+        }
     }
 }
 
 /// For hashing reports.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ReportHasher<E>(pub Report<E>);
 
 impl<E> From<Report<E>> for ReportHasher<E> {
@@ -208,6 +487,7 @@ pub enum Accessor {
     Field(&'static str),
     Index(usize),
     Key(String),
+    IntegerKey(i128),
 }
 
 impl Display for Accessor {
@@ -217,13 +497,23 @@ impl Display for Accessor {
             Self::Field(field) => write!(f, ".{field}"),
             Self::Index(index) => write!(f, "[{index}]"),
             Self::Key(key) => write!(f, "[\"{key}\"]"),
+            Self::IntegerKey(key) => write!(f, "[{key}]"),
         }
     }
 }
 
 /// Defines how a parent report collects a child report.
 pub trait Collector<E> {
-    fn apply(parent: &mut Report<E>, child: Report<E>) -> Result<(), Exit<E>>;
+    /// Collect the child report into the parent report.
+    fn collect(parent: &mut Report<E>, child: Report<E>) -> Result<(), Exit<E>>;
+    /// Apply the child report to the parent report. Validators call this instead of
+    /// `collect`, so every collector emits the events of the `tracing` feature.
+    fn apply(parent: &mut Report<E>, child: Report<E>) -> Result<(), Exit<E>> {
+        #[cfg(feature = "tracing")]
+        crate::trace::child(&child);
+
+        Self::collect(parent, child)
+    }
 }
 
 /// An exit "error" that acts as a control flow within validators, collectors, etc.
@@ -231,7 +521,7 @@ pub trait Collector<E> {
 /// as soon as the first invalid is encountered. The validators following this invalid
 /// will not be ran, which can be good for performance if you only want the first invalid
 /// anyway.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum Exit<E> {
     /// Exit gracefully. Although in the context of `Result<_, Exit<E>>` this is considered
     /// an error, Exit::Gracefully indicates that this behavior was expected.
@@ -240,3 +530,358 @@ pub enum Exit<E> {
     /// is intended for force-exiting if a fatal error is encountered.
     WithError(E),
 }
+
+#[cfg(test)]
+mod tests {
+    use vate::{
+        path, Accessor, BoxedValidator, Bundle, Compare, DynValidator, Everything, Nested, Report,
+        StringAlphabetic, StringLengthRange, True, Validate, Validator,
+    };
+
+    #[test]
+    fn report_subtree() {
+        #[derive(Validate)]
+        struct Example1 {
+            #[vate(Nested)]
+            example2: Example2,
+            #[vate(True)]
+            b: bool,
+        }
+
+        #[derive(Validate)]
+        struct Example2 {
+            #[vate(StringAlphabetic)]
+            a: String,
+            #[vate(StringAlphabetic)]
+            b: String,
+        }
+
+        let example1 = Example1 {
+            example2: Example2 {
+                a: String::from("a"),
+                b: String::from("0"),
+            },
+            b: true,
+        };
+
+        let mut report = Report::new(Accessor::Root("example1"));
+        let _ = example1.validate::<Everything>(&(), &mut report);
+
+        assert_eq!(report.iter().count(), 5);
+        assert_eq!(report.count_leaves_at_path(path!(example1)).unwrap(), 3);
+        assert_eq!(
+            report
+                .count_leaves_at_path(path!(example1.example2))
+                .unwrap(),
+            2
+        );
+        assert!(report
+            .is_any_invalid_at_path(path!(example1.example2))
+            .unwrap());
+        assert!(!report.is_any_invalid_at_path(path!(example1.b)).unwrap());
+        assert!(report.get_at_path(path!(example1.c)).is_none());
+    }
+
+    #[test]
+    fn dyn_validator() {
+        let validators: Vec<BoxedValidator<String, (), (), Everything>> = vec![
+            StringAlphabetic.boxed(),
+            StringLengthRange::Chars { min: 1, max: 3 }.boxed(),
+        ];
+
+        let target = String::from("abcd");
+
+        let mut report = Report::new(Accessor::Root("example"));
+        for validator in &validators {
+            let _ = validator.run_dyn(Accessor::Field("a"), &target, &(), &mut report);
+        }
+
+        assert!(report.is_invalid());
+    }
+
+    #[test]
+    fn validator_passes() {
+        let validator = Bundle!(
+            StringAlphabetic,
+            StringLengthRange::Chars { min: 1, max: 3 }
+        );
+
+        assert!(Validator::<_, (), ()>::passes(&validator, &"abc", &()));
+        assert!(!Validator::<_, (), ()>::passes(&validator, &"abcd", &()));
+        assert!(!Validator::<_, (), ()>::passes(&validator, &"a0", &()));
+    }
+
+    #[test]
+    fn report_push_child_merges() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringAlphabetic, StringLengthRange::Chars { min: 1, max: 3 })]
+            a: String,
+            #[vate(StringAlphabetic, StringLengthRange::Chars { min: 1, max: 3 })]
+            b: String,
+        }
+
+        let example = Example {
+            a: String::from("abcd"),
+            b: String::from("abc0"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_invalid_at_path(path!(example.a)).unwrap());
+        assert_eq!(
+            report
+                .get_at_path(path!(example.b))
+                .unwrap()
+                .get_message()
+                .split("; ")
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn report_display_tree() {
+        #[derive(Validate)]
+        struct Example1 {
+            #[vate(Nested)]
+            example2: Example2,
+            #[vate(True)]
+            b: bool,
+        }
+
+        #[derive(Validate)]
+        struct Example2 {
+            #[vate(StringAlphabetic)]
+            a: String,
+        }
+
+        let example1 = Example1 {
+            example2: Example2 {
+                a: String::from("0"),
+            },
+            b: true,
+        };
+
+        let mut report = Report::new(Accessor::Root("example1"));
+        let _ = example1.validate::<Everything>(&(), &mut report);
+
+        assert_eq!(
+            format!("{report:#}"),
+            "example1 (invalid)\n  .b (valid)\n  .example2 (invalid)\n    .a (invalid): contains non-alphabetic characters\n"
+        );
+    }
+
+    #[test]
+    fn report_clone() {
+        let mut report = Report::<()>::new(Accessor::Root("example"));
+        let mut child_report = Report::new(Accessor::Field("a"));
+        child_report.set_invalid();
+        child_report.set_message("is invalid");
+        report.push_child(child_report);
+
+        let cloned = report.clone();
+        report.set_error(());
+
+        assert!(cloned.is_valid());
+        assert_eq!(
+            cloned
+                .get_child(&Accessor::Field("a"))
+                .unwrap()
+                .get_message(),
+            "is invalid"
+        );
+    }
+
+    #[test]
+    fn report_merge() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringAlphabetic)]
+            a: String,
+        }
+
+        let examples = [String::from("a"), String::from("0")].map(|a| Example { a });
+
+        let mut report = Report::new(Accessor::Root("examples"));
+        for (index, example) in examples.iter().enumerate() {
+            let mut example_report = Report::new(Accessor::Root("example"));
+            let _ = example.validate::<Everything>(&(), &mut example_report);
+            report.merge_child(Accessor::Index(index), example_report);
+        }
+
+        assert!(report.is_invalid());
+        assert!(report.is_valid_at_path(path!(examples[0].a)).unwrap());
+        assert!(report.is_invalid_at_path(path!(examples[1].a)).unwrap());
+
+        let mut example_report = Report::new(Accessor::Root("example"));
+        example_report.set_error(());
+        report.merge_child(Accessor::Index(2), example_report);
+
+        assert!(report.is_error());
+        assert!(report.get_at_path(path!(examples[2])).unwrap().is_error());
+    }
+
+    #[test]
+    fn validate_macro() {
+        let limit = 250;
+        let report: Report<()> = validate!(
+            limit,
+            root = "limit",
+            [Compare!( >= 1 ), Compare!( <= 100 )]
+        );
+
+        assert_eq!(report.get_accessor(), &Accessor::Root("limit"));
+        assert!(report.is_invalid());
+        assert!(report.get_message().contains("250"));
+
+        let name = String::from("vate");
+        let report: Report<()> = validate!(
+            name,
+            root = "name",
+            data = &(),
+            [
+                StringAlphabetic,
+                StringLengthRange::Chars { min: 1, max: 8 }
+            ]
+        );
+
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn validate_at() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringAlphabetic)]
+            a: String,
+            #[vate(StringAlphabetic)]
+            b: String,
+        }
+
+        let example = Example {
+            a: String::from("a"),
+            b: String::from("0"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate_at::<Everything>(&[&[Accessor::Field("a")]], &(), &mut report);
+
+        assert!(report.is_valid());
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.get_at_path(path!(example.b)).is_none());
+    }
+
+    #[test]
+    fn validate_at_nested() {
+        #[derive(Validate)]
+        struct Example1 {
+            #[vate(StringAlphabetic)]
+            a: String,
+            #[vate(Nested)]
+            example2: Example2,
+            #[vate(each(StringAlphabetic))]
+            tags: Vec<String>,
+        }
+
+        #[derive(Validate)]
+        struct Example2 {
+            #[vate(StringAlphabetic)]
+            b: String,
+            #[vate(StringAlphabetic)]
+            c: String,
+        }
+
+        let example1 = Example1 {
+            a: String::from("0"),
+            example2: Example2 {
+                b: String::from("1"),
+                c: String::from("c"),
+            },
+            tags: vec![String::from("2"), String::from("d")],
+        };
+
+        let mut report = Report::new(Accessor::Root("example1"));
+        let _ = example1.validate_at::<Everything>(
+            &[
+                &path!(example1.example2.c)[1..],
+                &path!(example1.tags[1])[1..],
+            ],
+            &(),
+            &mut report,
+        );
+
+        assert!(report.is_invalid());
+        assert!(report.get_at_path(path!(example1.a)).is_none());
+        assert!(report.get_at_path(path!(example1.example2.b)).is_none());
+        assert!(report.is_valid_at_path(path!(example1.example2.c)).unwrap());
+        // Other validators can't validate part of a field, so all of the tags are validated.
+        assert!(report.is_invalid_at_path(path!(example1.tags[0])).unwrap());
+
+        let mut report = Report::new(Accessor::Root("example1"));
+        let _ =
+            example1.validate_at::<Everything>(&[&[Accessor::Field("example2")]], &(), &mut report);
+
+        assert!(report
+            .is_invalid_at_path(path!(example1.example2.b))
+            .unwrap());
+        assert!(report.get_at_path(path!(example1.tags)).is_none());
+
+        let mut report = Report::new(Accessor::Root("example1"));
+        let _ = example1.validate_at::<Everything>(&[&[]], &(), &mut report);
+
+        assert!(report.is_invalid_at_path(path!(example1.a)).unwrap());
+    }
+
+    #[test]
+    fn validate_at_unknown() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringAlphabetic)]
+            email: String,
+        }
+
+        let example = Example {
+            email: String::from("0"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate_at::<Everything>(
+            &[&[Accessor::Field("emial")], &[Accessor::Index(0)]],
+            &(),
+            &mut report,
+        );
+
+        assert!(report.is_invalid());
+        assert_eq!(
+            report
+                .get_at_path(path!(example.emial))
+                .unwrap()
+                .get_message(),
+            "is not a field of Example"
+        );
+        assert!(report.is_invalid_at_path(path!(example[0])).unwrap());
+        assert!(report.get_at_path(path!(example.email)).is_none());
+    }
+
+    #[test]
+    fn validate_iter() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringAlphabetic)]
+            a: String,
+        }
+
+        let lines = ["a", "0", "b"].into_iter();
+        let examples = lines.map(|a| Example { a: String::from(a) });
+
+        let mut report = Report::new(Accessor::Root("examples"));
+        let _ = vate::validate_iter::<_, Everything>(examples, &(), &mut report);
+
+        assert!(report.is_invalid());
+        assert!(report.is_valid_at_path(path!(examples[0].a)).unwrap());
+        assert!(report.is_invalid_at_path(path!(examples[1].a)).unwrap());
+        assert!(report.is_valid_at_path(path!(examples[2].a)).unwrap());
+    }
+}
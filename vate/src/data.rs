@@ -0,0 +1,116 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt::{Debug, Formatter, Result as FmtResult},
+};
+
+/// A map of values keyed by their type.
+/// Can be used as the data type of a validation to let validators request
+/// independent pieces of data, instead of all of them depending on one concrete type.
+#[derive(Default)]
+pub struct DataMap {
+    /// The values, keyed by the type ID of the value.
+    values: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+}
+
+impl DataMap {
+    /// Create a new, empty data map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Insert a value, returning the previous value of the same type (if any).
+    pub fn insert<T: Any + Send + Sync>(&mut self, value: T) -> Option<T> {
+        self.values
+            .insert(TypeId::of::<T>(), Box::new(value))
+            .and_then(|previous| previous.downcast().ok())
+            .map(|previous| *previous)
+    }
+    /// Insert a value, consuming and returning the map.
+    pub fn with<T: Any + Send + Sync>(mut self, value: T) -> Self {
+        self.insert(value);
+        self
+    }
+    /// Get the value of a type.
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.values.get(&TypeId::of::<T>())?.downcast_ref()
+    }
+    /// Get the value of a type mutably.
+    pub fn get_mut<T: Any>(&mut self) -> Option<&mut T> {
+        self.values.get_mut(&TypeId::of::<T>())?.downcast_mut()
+    }
+    /// Remove the value of a type, returning it (if any).
+    pub fn remove<T: Any>(&mut self) -> Option<T> {
+        self.values
+            .remove(&TypeId::of::<T>())
+            .and_then(|value| value.downcast().ok())
+            .map(|value| *value)
+    }
+    /// Check if the map contains a value of a type.
+    pub fn contains<T: Any>(&self) -> bool {
+        self.values.contains_key(&TypeId::of::<T>())
+    }
+    /// Get the number of values in the map.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+    /// Check if the map contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl Debug for DataMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("DataMap")
+            .field("len", &self.values.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vate::{path, Accessor, Compare, DataMap, Everything, Report, Validate};
+
+    struct Limits {
+        max_quantity: u32,
+    }
+
+    struct Stock(u32);
+
+    #[test]
+    fn data_map() {
+        #[derive(Validate)]
+        #[vate(data = DataMap)]
+        struct Order {
+            #[vate(Compare!( <= data.get::<Limits>().unwrap().max_quantity ))]
+            quantity: u32,
+            #[vate(Compare!( <= data.get::<Stock>().unwrap().0 ))]
+            reserved: u32,
+        }
+
+        let order = Order {
+            quantity: 5,
+            reserved: 8,
+        };
+        let data = DataMap::new()
+            .with(Limits { max_quantity: 10 })
+            .with(Stock(7));
+
+        let mut report = Report::new(Accessor::Root("order"));
+        let _ = order.validate::<Everything>(&data, &mut report);
+
+        assert!(report.is_valid_at_path(path!(order.quantity)).unwrap());
+        assert!(report.is_invalid_at_path(path!(order.reserved)).unwrap());
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut data = DataMap::new();
+
+        assert!(data.insert(Stock(1)).is_none());
+        assert_eq!(data.insert(Stock(2)).unwrap().0, 1);
+        assert_eq!(data.get::<Stock>().unwrap().0, 2);
+        assert_eq!(data.remove::<Stock>().unwrap().0, 2);
+        assert!(data.is_empty());
+    }
+}
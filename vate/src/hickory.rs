@@ -0,0 +1,51 @@
+use ::hickory_resolver::{proto::rr::rdata::MX, ResolveError, TokioResolver};
+
+use crate::MailDomainResolver;
+
+/// Check if the MX records of a domain accept email. A single MX record with the root as
+/// its exchange is a null MX record, which means the domain doesn't accept email.
+fn accepts_mail<'a>(records: impl IntoIterator<Item = &'a MX>) -> bool {
+    let records = records.into_iter().collect::<Vec<_>>();
+    match records.as_slice() {
+        [] => false,
+        [record] => !record.exchange().is_root(),
+        _ => true,
+    }
+}
+
+/// Looks up the MX records of a domain, falling back to its A and AAAA records if it has
+/// no MX records.
+impl MailDomainResolver<ResolveError> for TokioResolver {
+    async fn accepts_mail(&self, domain: &str) -> Result<bool, ResolveError> {
+        // Fully qualify the domain, so that search domains are not appended.
+        let domain = format!("{domain}.");
+        match self.mx_lookup(domain.as_str()).await {
+            Ok(lookup) => Ok(accepts_mail(lookup.iter())),
+            Err(error) if error.is_no_records_found() => match self.lookup_ip(domain).await {
+                Ok(lookup) => Ok(lookup.iter().next().is_some()),
+                Err(error) if error.is_no_records_found() => Ok(false),
+                Err(error) => Err(error),
+            },
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hickory_resolver::{proto::rr::rdata::MX, Name};
+
+    use super::accepts_mail;
+
+    #[test]
+    fn null_mx() {
+        let mail = MX::new(10, Name::from_ascii("mail.example.com.").unwrap());
+        let backup = MX::new(20, Name::from_ascii("backup.example.com.").unwrap());
+        let null = MX::new(0, Name::root());
+
+        assert!(accepts_mail([&mail]));
+        assert!(accepts_mail([&mail, &backup]));
+        assert!(!accepts_mail([&null]));
+        assert!(!accepts_mail([]));
+    }
+}
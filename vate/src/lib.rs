@@ -2,30 +2,172 @@ extern crate self as vate;
 
 mod collectors;
 mod core;
+mod data;
+mod registry;
+mod reports;
+mod revalidator;
+#[cfg(feature = "tracing")]
+mod trace;
+mod valid;
 mod validators;
 
-pub use collectors::{Everything, FirstInvalidAndPrecedingErrors, InvalidsAndErrors};
-pub use core::{Accessor, Collector, Exit, Report, ReportHasher, Validate, Validator};
+pub use collectors::{
+    ErrorsOnly, Everything, FirstInvalidAndPrecedingErrors, FirstPerField, InvalidsAndErrors,
+    ValidityOnly,
+};
+pub use core::{
+    validate_iter, Accessor, BoxedValidator, Collector, DynValidator, Exit, FromData, Report,
+    ReportHasher, Validate, Validator,
+};
+pub use data::DataMap;
+pub use registry::{RegistryError, Rule, RuleSet, ValidatorRegistry};
+pub use reports::{
+    FailureCounts, HtmlReport, MapReport, MetricsReport, ProblemDetailsItem, ProblemDetailsReport,
+    SeverityReport,
+};
+pub use revalidator::Revalidator;
+pub use valid::{Valid, ValidateExt};
+#[cfg(feature = "globset")]
+pub use validators::glob::{StringGlobPattern, StringMatchesGlob};
+#[cfg(feature = "image")]
+pub use validators::image::{
+    BytesImageDimensionsAtMost, BytesImageFormatIn, BytesImagePixelsAtMost, ImageFormat,
+};
+#[cfg(feature = "json")]
+pub use validators::json::StringJsonText;
+#[cfg(feature = "jwt")]
+pub use validators::jwt::{StringJwtAlgorithmIn, StringJwtFormat, StringJwtTypeIn};
+#[cfg(feature = "prost")]
+pub use validators::prost::{BytesMessage, EnumDefinedOnly};
+#[cfg(feature = "url")]
+pub use validators::url::{
+    StringUrl, StringUrlHostIn, StringUrlNoPrivateHost, StringUrlNoUserinfo, StringUrlSchemeIn,
+};
+#[cfg(feature = "xml")]
+pub use validators::xml::{StringXmlRoot, StringXmlText};
 pub use validators::{
     boolean::{False, True},
+    budget::{Budgeted, ValidationBudget},
     bundle::Bundle2,
+    checksum::{BytesCrc32Matches, StringDamm, StringLuhn, StringVerhoeff},
     collection::CollectionIterate,
     compare::{
-        CompareEqualTo, CompareGreaterThan, CompareGreaterThanOrEqualTo, CompareLessThan,
-        CompareLessThanOrEqualTo, CompareNotEqualTo,
+        CompareDataEqualTo, CompareDataGreaterThan, CompareDataGreaterThanOrEqualTo,
+        CompareDataLessThan, CompareDataLessThanOrEqualTo, CompareDataNotEqualTo, CompareEqualTo,
+        CompareGreaterThan, CompareGreaterThanOrEqualTo, CompareLessThan, CompareLessThanOrEqualTo,
+        CompareNotEqualTo,
+    },
+    datetime::{
+        CalendarDate, Clock, DateAgeAtLeast, DateTimeAfterNow, DateTimeBeforeNow, DateTimeWithin,
+        FixedClock, SystemClock, Timestamp,
     },
+    email::{
+        MailDomainResolver, MailDomains, ResolvedMailDomains, StringEmail, StringEmailDeliverable,
+    },
+    finance::{CardBrand, StringCreditCard},
+    geo::{Coordinates, CoordinatesWithinBoundingBox, CoordinatesWithinPolygon},
+    html::StringHtmlRestricted,
     iterator::{
-        ExactSizeIteratorLengthEquals, IteratorIndexed, IteratorKeyed, IteratorLengthEquals,
+        ExactSizeIteratorLengthEquals, IteratorIndexed, IteratorIntegerKeyed, IteratorKeyed,
+        IteratorKeysValues, IteratorLengthEquals,
     },
-    nested::Nested,
+    nested::{Nested, NestedAt},
     option::{OptionNone, OptionSome, OptionSomeThen},
+    password::{PasswordHashAlgorithm, StringPasswordHashFormat},
+    severity::{Severity, WithSeverity},
     string::{
-        StringAlphabetic, StringAlphanumeric, StringAscii, StringLengthEquals, StringLengthRange,
-        StringMatchesRegex,
+        StringAlphabetic, StringAlphanumeric, StringAscii, StringBase64, StringHexadecimal,
+        StringHexadecimalLength, StringHostname, StringIdentifier, StringLengthEquals,
+        StringLengthRange, StringMatchesRegex, StringNotBlank, StringParsedAs,
+        StringParsesAsFloatWithin, StringParsesAsIntegerWithin, StringTrimmed,
     },
+    uniqueness::{CheckedValues, StringExists, StringUnique, TakenValues, UniquenessChecker},
 };
-pub use vate_derive::{path, Validate};
+pub use vate_derive::{path, regex, Validate};
+
+#[cfg(feature = "async-graphql")]
+pub mod async_graphql;
+
+#[cfg(feature = "axum")]
+pub mod axum;
+
+#[cfg(feature = "hickory")]
+pub mod hickory;
+
+#[cfg(feature = "poem")]
+pub mod poem;
+
+#[cfg(feature = "rocket")]
+pub mod rocket;
+
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+
+#[cfg(feature = "validator")]
+pub mod validator;
+
+#[cfg(feature = "warp")]
+pub mod warp;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 pub mod extras {
+    #[cfg(feature = "globset")]
+    pub use globset::{Glob, GlobMatcher};
     pub use regex::Regex;
 }
+
+/// Items used by macros, which are not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    use crate::{Accessor, Collector, Exit, Report};
+
+    pub use crate::validators::compare::compare_owned;
+
+    /// Keeps the span of a derived `Validate::validate` entered until it is dropped.
+    pub struct ValidateSpan {
+        #[cfg(feature = "tracing")]
+        _entered: ::tracing::span::EnteredSpan,
+    }
+
+    /// Enter the span of a derived `Validate::validate`, which does nothing without the
+    /// `tracing` feature.
+    pub fn validate_span<E>(type_name: &'static str, report: &Report<E>) -> ValidateSpan {
+        #[cfg(feature = "tracing")]
+        return ValidateSpan {
+            _entered: crate::trace::validate_span(type_name, report.get_accessor()).entered(),
+        };
+        #[cfg(not(feature = "tracing"))]
+        {
+            let _ = (type_name, report);
+            ValidateSpan {}
+        }
+    }
+
+    /// Add an invalid report for each path of a derived `Validate::validate_at` that doesn't
+    /// start with a field of the target.
+    pub fn validate_unknown_fields<C: Collector<E>, E>(
+        type_name: &'static str,
+        fields: &[&str],
+        paths: &[&[Accessor]],
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let mut unknown = Vec::new();
+        for path in paths {
+            let is_field = matches!(&path[0], Accessor::Field(field) if fields.contains(field));
+            if !is_field && !unknown.contains(&path[0]) {
+                unknown.push(path[0].clone());
+            }
+        }
+
+        for accessor in unknown {
+            let mut child_report = Report::new(accessor);
+            child_report.set_invalid();
+            child_report.set_message(format!("is not a field of {type_name}"));
+            C::apply(parent_report, child_report)?;
+        }
+
+        Ok(())
+    }
+}
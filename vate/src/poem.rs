@@ -0,0 +1,97 @@
+use ::poem::{
+    http::{header, HeaderValue, StatusCode},
+    web::Json,
+    Error, FromRequest, IntoResponse, Request, RequestBody, Response, Result,
+};
+use serde::de::DeserializeOwned;
+
+use crate::{Accessor, InvalidsAndErrors, ProblemDetailsReport, Valid, Validate, ValidateExt};
+
+/// Extracts a JSON request body and validates it.
+/// The data passed to validators is the request data of its type, added with `Route::data`
+/// or the `AddData` middleware. If the body is not valid, the request is rejected with a
+/// `422 Unprocessable Content` problem details response.
+pub struct ValidatedJson<T>(pub T);
+
+impl<'a, T> FromRequest<'a> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate + Send,
+    T::Data: Send + Sync + 'static,
+{
+    async fn from_request(request: &'a Request, body: &mut RequestBody) -> Result<Self> {
+        let Json(target) = Json::<T>::from_request(request, body).await?;
+
+        let data = request.data::<T::Data>().ok_or_else(|| {
+            Error::from_string("missing validation data", StatusCode::INTERNAL_SERVER_ERROR)
+        })?;
+
+        target
+            .into_valid::<InvalidsAndErrors>(Accessor::Root("body"), data)
+            .map(|valid| Self(Valid::into_inner(valid)))
+            .map_err(|report| {
+                Error::from_response(problem_details_response(&ProblemDetailsReport::from(
+                    &report,
+                )))
+            })
+    }
+}
+
+/// Create a problem details response.
+fn problem_details_response(problem_details: &ProblemDetailsReport) -> Response {
+    let status =
+        StatusCode::from_u16(problem_details.status).unwrap_or(StatusCode::UNPROCESSABLE_ENTITY);
+    let mut response = Json(problem_details).with_status(status).into_response();
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static(ProblemDetailsReport::CONTENT_TYPE),
+    );
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use poem::{http::StatusCode, FromRequest, Request, RequestBody};
+    use serde::Deserialize;
+    use vate::{poem::ValidatedJson, StringAlphabetic, Validate};
+
+    #[derive(Deserialize, Validate)]
+    struct Example {
+        #[vate(StringAlphabetic)]
+        a: String,
+    }
+
+    fn request(body: &'static str) -> (Request, RequestBody) {
+        let mut request = Request::builder()
+            .content_type("application/json")
+            .body(body);
+        request.set_data(());
+        request.split()
+    }
+
+    #[tokio::test]
+    async fn validated_json() {
+        let (request, mut body) = request(r#"{"a":"a"}"#);
+
+        let ValidatedJson(example) = ValidatedJson::<Example>::from_request(&request, &mut body)
+            .await
+            .ok()
+            .unwrap();
+
+        assert_eq!(example.a, "a");
+    }
+
+    #[tokio::test]
+    async fn validated_json_invalid() {
+        let (request, mut body) = request(r#"{"a":"0"}"#);
+
+        let error = ValidatedJson::<Example>::from_request(&request, &mut body)
+            .await
+            .err()
+            .unwrap();
+
+        let response = error.into_response();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(response.content_type(), Some("application/problem+json"));
+    }
+}
@@ -0,0 +1,294 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+};
+
+use crate::{Accessor, BoxedValidator, Collector, Exit, Report};
+
+/// Builds a validator from its parameters, or fails with a message explaining why
+/// the parameters are not valid.
+type Builder<'a, T, D, E, C> =
+    Box<dyn Fn(&[&str]) -> Result<BoxedValidator<'a, T, D, E, C>, String> + 'a>;
+
+/// A registry of validators keyed by name, which builds boxed validators from
+/// string parameters at runtime.
+pub struct ValidatorRegistry<'a, T, D, E, C: Collector<E>> {
+    /// The builders, keyed by name.
+    builders: HashMap<String, Builder<'a, T, D, E, C>>,
+}
+
+impl<'a, T, D, E, C: Collector<E>> ValidatorRegistry<'a, T, D, E, C> {
+    /// Create a new, empty registry.
+    pub fn new() -> Self {
+        Self {
+            builders: HashMap::new(),
+        }
+    }
+    /// Register a builder under a name, replacing the previous builder of that name (if any).
+    /// The builder parses the parameters into a validator, or returns a message explaining
+    /// why they are not valid.
+    pub fn register<F>(&mut self, name: impl Into<String>, builder: F)
+    where
+        F: Fn(&[&str]) -> Result<BoxedValidator<'a, T, D, E, C>, String> + 'a,
+    {
+        self.builders.insert(name.into(), Box::new(builder));
+    }
+    /// Register a builder under a name, consuming and returning the registry.
+    pub fn with<F>(mut self, name: impl Into<String>, builder: F) -> Self
+    where
+        F: Fn(&[&str]) -> Result<BoxedValidator<'a, T, D, E, C>, String> + 'a,
+    {
+        self.register(name, builder);
+        self
+    }
+    /// Build the validator registered under a name.
+    pub fn build(
+        &self,
+        name: &str,
+        params: &[&str],
+    ) -> Result<BoxedValidator<'a, T, D, E, C>, RegistryError> {
+        let builder = self
+            .builders
+            .get(name)
+            .ok_or_else(|| RegistryError::Unregistered(name.to_string()))?;
+        builder(params).map_err(|message| RegistryError::InvalidParams {
+            name: name.to_string(),
+            message,
+        })
+    }
+    /// Check if a validator is registered under a name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.builders.contains_key(name)
+    }
+}
+
+impl<T, D, E, C: Collector<E>> Default for ValidatorRegistry<'_, T, D, E, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, D, E, C: Collector<E>> Debug for ValidatorRegistry<'_, T, D, E, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ValidatorRegistry")
+            .field("names", &self.builders.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// The error of building a validator from a registry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegistryError {
+    /// No validator is registered under the name.
+    Unregistered(String),
+    /// The builder rejected the parameters.
+    InvalidParams { name: String, message: String },
+}
+
+impl Display for RegistryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Unregistered(name) => write!(f, "no validator is registered as \"{name}\""),
+            Self::InvalidParams { name, message } => {
+                write!(f, "invalid parameters for \"{name}\": {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+/// A rule of a `RuleSet`, which runs the validator registered under a name on a field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rule {
+    /// The field the validator runs on.
+    pub field: String,
+    /// The name of the validator in the registry.
+    pub validator: String,
+    /// The parameters of the validator.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub params: Vec<String>,
+}
+
+/// Validators built from rules by a registry, such as rules defined at runtime for each
+/// tenant of a product. The rules validate the values of fields looked up by name, such as
+/// the values of a map or a JSON object.
+pub struct RuleSet<'a, T, D, E, C: Collector<E>> {
+    /// The fields and the validators that run on them, in the order of the rules.
+    rules: Vec<(String, BoxedValidator<'a, T, D, E, C>)>,
+}
+
+impl<'a, T, D, E, C: Collector<E>> RuleSet<'a, T, D, E, C> {
+    /// Build the validators of the rules, failing at the first rule that can't be built.
+    pub fn build(
+        registry: &ValidatorRegistry<'a, T, D, E, C>,
+        rules: &[Rule],
+    ) -> Result<Self, RegistryError> {
+        let rules = rules
+            .iter()
+            .map(|rule| {
+                let params = rule.params.iter().map(String::as_str).collect::<Vec<_>>();
+                let validator = registry.build(&rule.validator, &params)?;
+                Ok((rule.field.clone(), validator))
+            })
+            .collect::<Result<_, RegistryError>>()?;
+        Ok(Self { rules })
+    }
+    /// Run the rules on the values of their fields, which are validated with `Accessor::Key`.
+    /// Fields without a value are invalid.
+    pub fn apply<'t, F>(
+        &self,
+        get: F,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>>
+    where
+        F: Fn(&str) -> Option<&'t T>,
+        T: 't,
+    {
+        let mut missing = Vec::new();
+
+        for (field, validator) in &self.rules {
+            match get(field) {
+                Some(value) => {
+                    validator.run_dyn(Accessor::Key(field.clone()), value, data, parent_report)?
+                }
+                None if !missing.contains(&field) => {
+                    missing.push(field);
+                    let mut child_report = Report::new(Accessor::Key(field.clone()));
+                    child_report.set_invalid();
+                    child_report.set_message(String::from("is missing"));
+                    C::apply(parent_report, child_report)?;
+                }
+                None => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, D, E, C: Collector<E>> Debug for RuleSet<'_, T, D, E, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("RuleSet")
+            .field(
+                "fields",
+                &self
+                    .rules
+                    .iter()
+                    .map(|(field, _)| field)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use vate::{
+        path, Accessor, DynValidator, Everything, RegistryError, Report, Rule, RuleSet,
+        StringAlphabetic, StringLengthRange, ValidatorRegistry,
+    };
+
+    #[test]
+    fn validator_registry() {
+        let registry = ValidatorRegistry::<String, (), (), Everything>::new()
+            .with("alphabetic", |_| Ok(StringAlphabetic.boxed()))
+            .with("length_chars_between", |params| {
+                let [min, max] = params else {
+                    return Err(String::from("expected a minimum and a maximum"));
+                };
+                let min = min.parse().map_err(|_| String::from("invalid minimum"))?;
+                let max = max.parse().map_err(|_| String::from("invalid maximum"))?;
+                Ok(StringLengthRange::Chars { min, max }.boxed())
+            });
+
+        let validator = registry.build("length_chars_between", &["1", "3"]).unwrap();
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = validator.run_dyn(
+            Accessor::Field("a"),
+            &String::from("abcd"),
+            &(),
+            &mut report,
+        );
+        assert!(report.is_invalid());
+
+        assert_eq!(
+            registry.build("length_chars_between", &["1"]).err(),
+            Some(RegistryError::InvalidParams {
+                name: String::from("length_chars_between"),
+                message: String::from("expected a minimum and a maximum"),
+            })
+        );
+        assert_eq!(
+            registry.build("numeric", &[]).err(),
+            Some(RegistryError::Unregistered(String::from("numeric")))
+        );
+    }
+
+    #[test]
+    fn rule_set() {
+        let registry = ValidatorRegistry::<String, (), (), Everything>::new()
+            .with("alphabetic", |_| Ok(StringAlphabetic.boxed()))
+            .with("length_chars_at_most", |params| {
+                let [max] = params else {
+                    return Err(String::from("expected a maximum"));
+                };
+                let max = max.parse().map_err(|_| String::from("invalid maximum"))?;
+                Ok(StringLengthRange::Chars { min: 0, max }.boxed())
+            });
+
+        let rule = |field: &str, validator: &str, params: &[&str]| Rule {
+            field: String::from(field),
+            validator: String::from(validator),
+            params: params.iter().map(|param| String::from(*param)).collect(),
+        };
+        let rules = [
+            rule("name", "alphabetic", &[]),
+            rule("name", "length_chars_at_most", &["4"]),
+            rule("city", "alphabetic", &[]),
+            rule("country", "alphabetic", &[]),
+        ];
+        let rule_set = RuleSet::build(&registry, &rules).unwrap();
+
+        let values = HashMap::from([
+            (String::from("name"), String::from("gintoki")),
+            (String::from("city"), String::from("edo")),
+        ]);
+
+        let mut report = Report::new(Accessor::Root("values"));
+        let _ = rule_set.apply(|field| values.get(field), &(), &mut report);
+
+        assert!(report.is_invalid());
+        assert!(report.is_invalid_at_path(path!(values["name"])).unwrap());
+        assert!(report.is_valid_at_path(path!(values["city"])).unwrap());
+        assert_eq!(
+            report
+                .get_at_path(path!(values["country"]))
+                .unwrap()
+                .get_message(),
+            "is missing"
+        );
+
+        assert_eq!(
+            RuleSet::build(&registry, &[rule("name", "numeric", &[])]).err(),
+            Some(RegistryError::Unregistered(String::from("numeric")))
+        );
+    }
+
+    #[cfg(all(feature = "serde", feature = "json"))]
+    #[test]
+    fn rule_deserialize() {
+        let rules: Vec<Rule> = serde_json::from_str(
+            r#"[{ "field": "name", "validator": "length_chars_at_most", "params": ["4"] }, { "field": "city", "validator": "alphabetic" }]"#,
+        )
+        .unwrap();
+
+        assert_eq!(rules[0].params, ["4"]);
+        assert!(rules[1].params.is_empty());
+    }
+}
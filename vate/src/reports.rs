@@ -0,0 +1,657 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+use crate::{Accessor, Report, Severity};
+
+/// A report that maps paths to the messages of invalid and erroneous reports.
+/// Paths are relative to the root report, such as `profile.hobbies[1]`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct MapReport {
+    /// The messages, keyed by path.
+    messages: HashMap<String, Vec<String>>,
+}
+
+impl MapReport {
+    /// Create a new, empty map report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Push a message to a path.
+    pub fn push(&mut self, path: impl Into<String>, message: impl Into<String>) {
+        self.messages
+            .entry(path.into())
+            .or_default()
+            .push(message.into());
+    }
+    /// Get the messages of a path.
+    pub fn get(&self, path: &str) -> Option<&[String]> {
+        self.messages.get(path).map(Vec::as_slice)
+    }
+    /// Iterate over the paths and their messages.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Vec<String>)> {
+        self.messages.iter()
+    }
+    /// Check if the report contains no messages.
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+    /// Join the messages into one line, each prefixed by its path, such as
+    /// `age is "0", which ..., name contains ...`. Paths are sorted, so the line is the same
+    /// every time.
+    pub fn summary(&self) -> String {
+        let mut paths = self.messages.iter().collect::<Vec<_>>();
+        paths.sort_by_key(|(path, _)| *path);
+        paths
+            .into_iter()
+            .flat_map(|(path, messages)| {
+                messages
+                    .iter()
+                    .map(move |message| format!("{path} {message}"))
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+    /// Unwrap the messages, keyed by path.
+    pub fn into_messages(self) -> HashMap<String, Vec<String>> {
+        self.messages
+    }
+}
+
+impl<E> From<&Report<E>> for MapReport {
+    fn from(report: &Report<E>) -> Self {
+        let mut map_report = Self::new();
+        for (path, report) in flatten(report) {
+            if !report.is_valid() && !report.get_message().is_empty() {
+                map_report.push(dotted_path(&path), report.get_message());
+            }
+        }
+        map_report
+    }
+}
+
+/// A report that splits the messages of invalid and erroneous reports by `Severity`, into a
+/// `MapReport` for each severity. Messages that were joined by merging are split again, so
+/// each keeps its own severity.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeverityReport {
+    /// The messages of errors, keyed by path.
+    errors: MapReport,
+    /// The messages of warnings, keyed by path.
+    warnings: MapReport,
+    /// The messages of infos, keyed by path.
+    infos: MapReport,
+}
+
+impl SeverityReport {
+    /// Get the messages of a severity, without their prefixes.
+    pub fn get(&self, severity: Severity) -> &MapReport {
+        match severity {
+            Severity::Error => &self.errors,
+            Severity::Warning => &self.warnings,
+            Severity::Info => &self.infos,
+        }
+    }
+    /// Get the messages of errors.
+    pub fn errors(&self) -> &MapReport {
+        &self.errors
+    }
+    /// Get the messages of warnings.
+    pub fn warnings(&self) -> &MapReport {
+        &self.warnings
+    }
+    /// Get the messages of infos.
+    pub fn infos(&self) -> &MapReport {
+        &self.infos
+    }
+    /// Check if the report contains errors. A report with only warnings and infos is still
+    /// invalid, so check this to accept it anyway.
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+    /// Check if the report contains warnings.
+    pub fn has_warnings(&self) -> bool {
+        !self.warnings.is_empty()
+    }
+}
+
+impl<E> From<&Report<E>> for SeverityReport {
+    fn from(report: &Report<E>) -> Self {
+        let mut severity_report = Self::default();
+        for (path, report) in flatten(report) {
+            if report.is_valid() || report.get_message().is_empty() {
+                continue;
+            }
+
+            let path = dotted_path(&path);
+            for message in report.get_message().split("; ") {
+                let (severity, message) = if report.is_error() {
+                    (Severity::Error, message)
+                } else {
+                    Severity::parse(message)
+                };
+                let map_report = match severity {
+                    Severity::Error => &mut severity_report.errors,
+                    Severity::Warning => &mut severity_report.warnings,
+                    Severity::Info => &mut severity_report.infos,
+                };
+                map_report.push(path.clone(), message);
+            }
+        }
+        severity_report
+    }
+}
+
+/// A report in the format of RFC 9457 problem details, meant to be serialized
+/// as an `application/problem+json` body. Each invalid and erroneous report
+/// with a message becomes an item of `errors`, sorted by pointer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProblemDetailsReport {
+    /// A URI reference identifying the problem type.
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    pub problem_type: String,
+    /// A short summary of the problem type.
+    pub title: String,
+    /// The HTTP status code.
+    pub status: u16,
+    /// An explanation specific to this occurrence of the problem.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub detail: Option<String>,
+    /// The invalid and erroneous targets.
+    pub errors: Vec<ProblemDetailsItem>,
+}
+
+impl ProblemDetailsReport {
+    /// The media type of problem details serialized as JSON.
+    pub const CONTENT_TYPE: &'static str = "application/problem+json";
+
+    /// Set the problem type.
+    pub fn with_type(mut self, problem_type: impl Into<String>) -> Self {
+        self.problem_type = problem_type.into();
+        self
+    }
+    /// Set the title.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+    /// Set the status.
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+    /// Set the detail.
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+}
+
+impl<E> From<&Report<E>> for ProblemDetailsReport {
+    fn from(report: &Report<E>) -> Self {
+        let mut errors = flatten(report)
+            .into_iter()
+            .filter(|(_, report)| !report.is_valid() && !report.get_message().is_empty())
+            .map(|(path, report)| ProblemDetailsItem {
+                pointer: json_pointer(&path),
+                code: String::from(if report.is_error() {
+                    "error"
+                } else {
+                    "invalid"
+                }),
+                message: report.get_message().clone(),
+            })
+            .collect::<Vec<_>>();
+        errors.sort_by(|a, b| a.pointer.cmp(&b.pointer));
+
+        Self {
+            problem_type: String::from("about:blank"),
+            title: String::from("Unprocessable Content"),
+            status: 422,
+            detail: None,
+            errors,
+        }
+    }
+}
+
+/// A report that counts the invalid and erroneous reports with a message, keyed by path,
+/// without collecting their messages. Indices and keys are replaced by `[*]`, such as
+/// `profile.hobbies[*]`, so the number of paths is bounded by the shape of the target.
+/// Reports of many validations can be recorded into one metrics report, which can be exported
+/// in the Prometheus text format.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct MetricsReport {
+    /// The counts, keyed by path.
+    counts: BTreeMap<String, FailureCounts>,
+}
+
+impl MetricsReport {
+    /// Create a new, empty metrics report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Count the invalid and erroneous reports of another report.
+    pub fn record<E>(&mut self, report: &Report<E>) {
+        for (path, report) in flatten(report) {
+            if report.is_valid() || report.get_message().is_empty() {
+                continue;
+            }
+
+            let counts = self.counts.entry(pattern_path(&path)).or_default();
+            if report.is_error() {
+                counts.errors += 1;
+            } else {
+                counts.invalid += 1;
+            }
+        }
+    }
+    /// Get the counts of a path.
+    pub fn get(&self, path: &str) -> Option<&FailureCounts> {
+        self.counts.get(path)
+    }
+    /// Iterate over the paths and their counts, sorted by path.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &FailureCounts)> {
+        self.counts.iter()
+    }
+    /// Check if the report contains no counts.
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+    /// Render the counts in the Prometheus text format, as a counter with the given name
+    /// labeled by `path` and `validity`.
+    pub fn to_prometheus(&self, name: &str) -> String {
+        let mut text = format!("# TYPE {name} counter\n");
+        for (path, counts) in &self.counts {
+            let path = path
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n");
+            for (validity, count) in [("invalid", counts.invalid), ("error", counts.errors)] {
+                if count > 0 {
+                    text.push_str(&format!(
+                        "{name}{{path=\"{path}\",validity=\"{validity}\"}} {count}\n"
+                    ));
+                }
+            }
+        }
+        text
+    }
+}
+
+impl<E> From<&Report<E>> for MetricsReport {
+    fn from(report: &Report<E>) -> Self {
+        let mut metrics_report = Self::new();
+        metrics_report.record(report);
+        metrics_report
+    }
+}
+
+/// The counts of a path in a `MetricsReport`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FailureCounts {
+    /// The number of invalid reports.
+    pub invalid: u64,
+    /// The number of erroneous reports.
+    pub errors: u64,
+}
+
+/// A report rendered as a standalone HTML table, with a row for each invalid and erroneous
+/// report with a message, sorted by path. Paths and messages are escaped, so messages that
+/// include user input are safe to embed in a page.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HtmlReport {
+    /// The rendered table.
+    html: String,
+}
+
+impl HtmlReport {
+    /// Get the rendered table.
+    pub fn as_str(&self) -> &str {
+        &self.html
+    }
+    /// Unwrap the rendered table.
+    pub fn into_string(self) -> String {
+        self.html
+    }
+}
+
+impl<E> From<&Report<E>> for HtmlReport {
+    fn from(report: &Report<E>) -> Self {
+        let mut rows = flatten(report)
+            .into_iter()
+            .filter(|(_, report)| !report.is_valid() && !report.get_message().is_empty())
+            .map(|(path, report)| {
+                let code = if report.is_error() {
+                    "error"
+                } else {
+                    "invalid"
+                };
+                (dotted_path(&path), code, report.get_message())
+            })
+            .collect::<Vec<_>>();
+        rows.sort();
+
+        let mut html = String::from(
+            "<table class=\"vate-report\">\n<thead><tr><th>Path</th><th>Code</th><th>Message</th></tr></thead>\n<tbody>\n",
+        );
+        for (path, code, message) in rows {
+            html.push_str(&format!(
+                "<tr class=\"vate-{code}\"><td>{}</td><td>{code}</td><td>{}</td></tr>\n",
+                escape_html(&path),
+                escape_html(message),
+            ));
+        }
+        html.push_str("</tbody>\n</table>\n");
+
+        Self { html }
+    }
+}
+
+impl Display for HtmlReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.html)
+    }
+}
+
+/// Escape text for HTML.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// An invalid or erroneous target in a `ProblemDetailsReport`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProblemDetailsItem {
+    /// A JSON pointer to the target, such as `#/profile/hobbies/1`.
+    pub pointer: String,
+    /// Either `invalid` or `error`.
+    pub code: String,
+    /// The message of the report.
+    pub message: String,
+}
+
+/// Flatten a report into its descendants, paired with their paths relative to the report.
+pub(crate) fn flatten<E>(report: &Report<E>) -> Vec<(Vec<&Accessor>, &Report<E>)> {
+    fn visit<'a, E>(
+        report: &'a Report<E>,
+        path: &[&'a Accessor],
+        flattened: &mut Vec<(Vec<&'a Accessor>, &'a Report<E>)>,
+    ) {
+        for child in report.children() {
+            let mut child_path = path.to_vec();
+            child_path.push(child.get_accessor());
+            visit(child, &child_path, flattened);
+            flattened.push((child_path, child));
+        }
+    }
+
+    let mut flattened = Vec::new();
+    visit(report, &[], &mut flattened);
+    flattened
+}
+
+/// Stringify a relative path, such as `profile.hobbies[1]`.
+pub(crate) fn dotted_path(path: &[&Accessor]) -> String {
+    let mut stringified = String::new();
+    for accessor in path {
+        match accessor {
+            Accessor::Field(field) if stringified.is_empty() => stringified.push_str(field),
+            accessor => stringified.push_str(&accessor.to_string()),
+        }
+    }
+    stringified
+}
+
+/// Stringify a relative path with indices and keys replaced by `[*]`, such as
+/// `profile.hobbies[*]`.
+pub(crate) fn pattern_path(path: &[&Accessor]) -> String {
+    let mut stringified = String::new();
+    for accessor in path {
+        match accessor {
+            Accessor::Field(field) if stringified.is_empty() => stringified.push_str(field),
+            Accessor::Root(_) | Accessor::Field(_) => stringified.push_str(&accessor.to_string()),
+            Accessor::Index(_) | Accessor::Key(_) | Accessor::IntegerKey(_) => {
+                stringified.push_str("[*]")
+            }
+        }
+    }
+    stringified
+}
+
+/// Stringify a relative path as a JSON pointer fragment, such as `#/profile/hobbies/1`.
+pub(crate) fn json_pointer(path: &[&Accessor]) -> String {
+    let mut stringified = String::from("#");
+    for accessor in path {
+        let token = match accessor {
+            Accessor::Root(root) => root.to_string(),
+            Accessor::Field(field) => field.to_string(),
+            Accessor::Index(index) => index.to_string(),
+            Accessor::Key(key) => key.to_string(),
+            Accessor::IntegerKey(key) => key.to_string(),
+        };
+        stringified.push('/');
+        stringified.push_str(&token.replace('~', "~0").replace('/', "~1"));
+    }
+    stringified
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use vate::{
+        Accessor, CollectionIterate, Compare, Everything, HtmlReport, IteratorKeyed, MapReport,
+        MetricsReport, Nested, ProblemDetailsReport, Report, StringAlphabetic, Validate,
+    };
+
+    #[test]
+    fn map_report() {
+        #[derive(Validate)]
+        struct Example1 {
+            #[vate(Nested)]
+            example2: Example2,
+            #[vate(CollectionIterate(IteratorKeyed(Compare!( != 2 ))))]
+            hm: HashMap<&'static str, u32>,
+        }
+
+        #[derive(Validate)]
+        struct Example2 {
+            #[vate(StringAlphabetic)]
+            a: String,
+            #[vate(StringAlphabetic)]
+            b: String,
+        }
+
+        let example1 = Example1 {
+            example2: Example2 {
+                a: String::from("a"),
+                b: String::from("0"),
+            },
+            hm: HashMap::from([("a", 1), ("b", 2)]),
+        };
+
+        let mut report = Report::new(Accessor::Root("example1"));
+        let _ = example1.validate::<Everything>(&(), &mut report);
+
+        let map_report = MapReport::from(&report);
+
+        assert_eq!(map_report.iter().count(), 2);
+        assert_eq!(
+            map_report.get("example2.b").unwrap(),
+            ["contains non-alphabetic characters"]
+        );
+        assert_eq!(
+            map_report.get("hm[\"b\"]").unwrap(),
+            ["is \"2\", which is equal to \"2\""]
+        );
+        assert_eq!(
+            map_report.summary(),
+            "example2.b contains non-alphabetic characters, hm[\"b\"] is \"2\", which is equal to \"2\""
+        );
+    }
+
+    #[test]
+    fn problem_details_report() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(CollectionIterate(IteratorKeyed(Compare!( != 2 ))))]
+            hm: HashMap<&'static str, u32>,
+        }
+
+        let example = Example {
+            hm: HashMap::from([("a/b", 2), ("c", 2), ("b", 2), ("d", 1)]),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        let problem_details = ProblemDetailsReport::from(&report).with_detail("Bad example.");
+
+        assert_eq!(problem_details.status, 422);
+        assert_eq!(
+            problem_details
+                .errors
+                .iter()
+                .map(|item| item.pointer.as_str())
+                .collect::<Vec<_>>(),
+            ["#/hm/a~1b", "#/hm/b", "#/hm/c"]
+        );
+        assert_eq!(problem_details.errors[0].code, "invalid");
+    }
+
+    #[test]
+    fn metrics_report() {
+        #[derive(Validate)]
+        struct Example1 {
+            #[vate(Nested)]
+            example2: Example2,
+            #[vate(CollectionIterate(IteratorKeyed(Compare!( != 2 ))))]
+            hm: HashMap<&'static str, u32>,
+        }
+
+        #[derive(Validate)]
+        struct Example2 {
+            #[vate(StringAlphabetic)]
+            a: String,
+        }
+
+        let mut metrics_report = MetricsReport::new();
+        for (a, b) in [("a", 2), ("0", 2), ("0", 1)] {
+            let example1 = Example1 {
+                example2: Example2 { a: String::from(a) },
+                hm: HashMap::from([("a", b), ("b\"", 2)]),
+            };
+
+            let mut report = Report::new(Accessor::Root("example1"));
+            let _ = example1.validate::<Everything>(&(), &mut report);
+            metrics_report.record(&report);
+        }
+
+        assert_eq!(metrics_report.iter().count(), 2);
+        assert_eq!(metrics_report.get("example2.a").unwrap().invalid, 2);
+        assert_eq!(metrics_report.get("hm[*]").unwrap().invalid, 5);
+        assert_eq!(metrics_report.get("hm[*]").unwrap().errors, 0);
+        assert!(metrics_report.get("example2").is_none());
+        assert_eq!(
+            metrics_report.to_prometheus("vate_failures_total"),
+            "# TYPE vate_failures_total counter\n\
+             vate_failures_total{path=\"example2.a\",validity=\"invalid\"} 2\n\
+             vate_failures_total{path=\"hm[*]\",validity=\"invalid\"} 5\n"
+        );
+    }
+
+    #[test]
+    fn html_report() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(CollectionIterate(IteratorKeyed(Compare!( != 2 ))))]
+            hm: HashMap<&'static str, u32>,
+        }
+
+        let example = Example {
+            hm: HashMap::from([("<b>", 1), ("<i>", 2)]),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        let html_report = HtmlReport::from(&report);
+
+        assert_eq!(html_report.as_str().matches("<tr class=").count(), 1);
+        assert!(html_report.as_str().contains(
+            "<td>hm[&quot;&lt;i&gt;&quot;]</td><td>invalid</td><td>is &quot;2&quot;, which is equal to &quot;2&quot;</td>"
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trip() {
+        use serde_test::{assert_tokens, Token};
+
+        let mut map_report = MapReport::new();
+        map_report.push("a", "contains non-alphabetic characters");
+
+        assert_tokens(
+            &map_report,
+            &[
+                Token::Map { len: Some(1) },
+                Token::Str("a"),
+                Token::Seq { len: Some(1) },
+                Token::Str("contains non-alphabetic characters"),
+                Token::SeqEnd,
+                Token::MapEnd,
+            ],
+        );
+
+        let report = Report::<()>::new(Accessor::Root("example"));
+        let problem_details = ProblemDetailsReport::from(&report);
+
+        assert_tokens(
+            &problem_details,
+            &[
+                Token::Struct {
+                    name: "ProblemDetailsReport",
+                    len: 4,
+                },
+                Token::Str("type"),
+                Token::Str("about:blank"),
+                Token::Str("title"),
+                Token::Str("Unprocessable Content"),
+                Token::Str("status"),
+                Token::U16(422),
+                Token::Str("errors"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{Accessor, Collector, Exit, Report, Validate};
+
+/// Keeps the report of a target between validations, so that after the target changes, only
+/// the changed fields and the fields that were not valid are validated again. Useful for
+/// interactive forms, which revalidate as fields are edited.
+#[derive(Clone, Debug)]
+pub struct Revalidator<E> {
+    /// The report of the latest validations.
+    report: Report<E>,
+    /// Whether every field has been validated since the latest validation that exited early.
+    /// Collectors that exit early don't validate the remaining fields, so the report can't
+    /// tell which of those fields are invalid.
+    complete: bool,
+}
+
+impl<E> Revalidator<E> {
+    /// Create a revalidator with an empty report with the given root.
+    pub fn new(root: &'static str) -> Self {
+        Self {
+            report: Report::new(Accessor::Root(root)),
+            complete: false,
+        }
+    }
+    /// Validate the entire target, replacing the report.
+    pub fn validate<T, C>(&mut self, target: &T, data: &T::Data) -> Result<(), Exit<E>>
+    where
+        T: Validate<Error = E>,
+        C: Collector<E>,
+    {
+        self.report = Report::new(self.report.get_accessor().clone());
+        let result = target.validate::<C>(data, &mut self.report);
+        self.complete = result.is_ok();
+        result
+    }
+    /// Validate the changed paths of the target, along with the fields that were not valid,
+    /// replacing their reports. Paths are relative to the target, like the paths of
+    /// `Validate::validate_at`. The reports of the other fields are kept. If the target has
+    /// not been validated, or the latest validation exited early, the entire target is
+    /// validated instead.
+    pub fn revalidate<T, C>(
+        &mut self,
+        target: &T,
+        changed: &[&[Accessor]],
+        data: &T::Data,
+    ) -> Result<(), Exit<E>>
+    where
+        T: Validate<Error = E>,
+        C: Collector<E>,
+    {
+        if !self.complete || changed.iter().any(|path| path.is_empty()) {
+            return self.validate::<T, C>(target, data);
+        }
+
+        let invalid = self
+            .report
+            .children()
+            .filter(|child| child.iter().any(|report| !report.is_valid()))
+            .map(|child| [child.get_accessor().clone()])
+            .collect::<Vec<_>>();
+        let mut paths = changed.to_vec();
+        paths.extend(invalid.iter().map(|path| path.as_slice()));
+
+        let stale = self
+            .report
+            .children()
+            .map(Report::get_accessor)
+            .filter(|&accessor| {
+                paths
+                    .iter()
+                    .any(|path| path.len() == 1 && path[0] == *accessor)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        for accessor in &stale {
+            self.report.take_child(accessor);
+        }
+
+        let mut partial_report = Report::new(self.report.get_accessor().clone());
+        let result = target.validate_at::<C>(&paths, data, &mut partial_report);
+
+        let Self { report, complete } = self;
+        *complete = result.is_ok();
+        report.set_valid();
+        report.merge(partial_report);
+        if report.is_valid() && report.children().any(Report::is_invalid) {
+            report.set_invalid();
+        }
+
+        result
+    }
+    /// Get the report of the latest validations.
+    pub fn report(&self) -> &Report<E> {
+        &self.report
+    }
+    /// Unwrap the report of the latest validations.
+    pub fn into_report(self) -> Report<E> {
+        self.report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vate::{
+        path, Accessor, Everything, FirstInvalidAndPrecedingErrors, Revalidator, StringAlphabetic,
+        Validate,
+    };
+
+    #[test]
+    fn revalidator() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringAlphabetic)]
+            a: String,
+            #[vate(StringAlphabetic)]
+            b: String,
+            #[vate(StringAlphabetic)]
+            c: String,
+        }
+
+        let mut example = Example {
+            a: String::from("a"),
+            b: String::from("0"),
+            c: String::from("c"),
+        };
+
+        let mut revalidator = Revalidator::new("example");
+        let _ = revalidator.validate::<_, Everything>(&example, &());
+        assert!(revalidator.report().is_invalid());
+
+        example.b = String::from("b");
+        example.c = String::from("1");
+
+        // Only `a` changed, but `b` is revalidated too since it was invalid.
+        let _ = revalidator.revalidate::<_, Everything>(&example, &[&[Accessor::Field("a")]], &());
+        assert!(revalidator.report().is_valid());
+        assert!(revalidator
+            .report()
+            .is_valid_at_path(path!(example.b))
+            .unwrap());
+        assert!(revalidator
+            .report()
+            .is_valid_at_path(path!(example.c))
+            .unwrap());
+
+        let _ = revalidator.revalidate::<_, Everything>(&example, &[&[Accessor::Field("c")]], &());
+        assert!(revalidator.report().is_invalid());
+        assert!(revalidator
+            .report()
+            .is_invalid_at_path(path!(example.c))
+            .unwrap());
+    }
+
+    #[test]
+    fn revalidator_exited_early() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringAlphabetic)]
+            a: String,
+            #[vate(StringAlphabetic)]
+            b: String,
+        }
+
+        let mut example = Example {
+            a: String::from("0"),
+            b: String::from("1"),
+        };
+
+        let mut revalidator = Revalidator::new("example");
+        let _ = revalidator.validate::<_, FirstInvalidAndPrecedingErrors>(&example, &());
+        assert!(revalidator.report().is_invalid());
+        assert!(revalidator.report().get_at_path(path!(example.b)).is_none());
+
+        example.a = String::from("a");
+
+        // `b` was never validated, since the validation exited early at `a`.
+        let _ = revalidator.revalidate::<_, FirstInvalidAndPrecedingErrors>(
+            &example,
+            &[&[Accessor::Field("a")]],
+            &(),
+        );
+        assert!(revalidator.report().is_invalid());
+        assert!(revalidator
+            .report()
+            .is_invalid_at_path(path!(example.b))
+            .unwrap());
+
+        example.b = String::from("b");
+
+        let _ = revalidator.revalidate::<_, FirstInvalidAndPrecedingErrors>(
+            &example,
+            &[&[Accessor::Field("b")]],
+            &(),
+        );
+        assert!(revalidator.report().is_valid());
+    }
+}
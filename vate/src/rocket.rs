@@ -0,0 +1,159 @@
+use std::ops::Deref;
+
+use ::rocket::{
+    data::{self, Data, FromData},
+    http::Status,
+    outcome::Outcome,
+    Request,
+};
+
+use crate::{Accessor, Exit, InvalidsAndErrors, ProblemDetailsReport, Report, Validate};
+
+/// A data guard that runs another data guard, such as `Json<T>` or `Form<T>`, then
+/// validates the extracted target. The data passed to validators is the managed state of its
+/// type. If the target is not valid, the guard fails with `422 Unprocessable Content`, and
+/// catchers can get the report with `problem_details`.
+pub struct Validated<G>(pub G);
+
+impl<G> Validated<G> {
+    /// Unwrap the inner data guard.
+    pub fn into_inner(self) -> G {
+        self.0
+    }
+}
+
+impl<G> Deref for Validated<G> {
+    type Target = G;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[::rocket::async_trait]
+impl<'r, G> FromData<'r> for Validated<G>
+where
+    G: FromData<'r> + Deref + Send,
+    G::Target: Validate,
+    <G::Target as Validate>::Data: Send + Sync + 'static,
+{
+    type Error = ValidationError<G::Error>;
+
+    async fn from_data(request: &'r Request<'_>, data: Data<'r>) -> data::Outcome<'r, Self> {
+        let guard = match G::from_data(request, data).await {
+            Outcome::Success(guard) => guard,
+            Outcome::Error((status, error)) => {
+                return Outcome::Error((status, ValidationError::Extract(error)))
+            }
+            Outcome::Forward(forward) => return Outcome::Forward(forward),
+        };
+
+        let Some(data) = request.rocket().state::<<G::Target as Validate>::Data>() else {
+            return Outcome::Error((Status::InternalServerError, ValidationError::MissingData));
+        };
+
+        let mut report = Report::new(Accessor::Root("body"));
+        if let Err(Exit::WithError(error)) = guard.validate::<InvalidsAndErrors>(data, &mut report)
+        {
+            report.set_error(error);
+        }
+
+        if report.is_valid() {
+            Outcome::Success(Self(guard))
+        } else {
+            let problem_details = ProblemDetailsReport::from(&report);
+            request.local_cache(|| CachedReport(Some(problem_details)));
+            Outcome::Error((Status::UnprocessableEntity, ValidationError::Invalid))
+        }
+    }
+}
+
+/// The error of the `Validated` data guard.
+#[derive(Debug)]
+pub enum ValidationError<E> {
+    /// The target could not be extracted by the inner data guard.
+    Extract(E),
+    /// The validation data is not managed by Rocket.
+    MissingData,
+    /// The target was extracted, but is not valid. The report can be retrieved with
+    /// `problem_details`.
+    Invalid,
+}
+
+/// The report cached by `Validated` when the target is not valid.
+struct CachedReport(Option<ProblemDetailsReport>);
+
+/// Get the report of a request that failed the `Validated` data guard.
+/// Intended to be used by error catchers.
+pub fn problem_details<'r>(request: &'r Request<'_>) -> Option<&'r ProblemDetailsReport> {
+    request.local_cache(|| CachedReport(None)).0.as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use rocket::{
+        catch, catchers,
+        form::Form,
+        http::{ContentType, Status},
+        local::blocking::Client,
+        post, routes, FromForm, Request,
+    };
+    use vate::{
+        rocket::{problem_details, Validated},
+        StringAlphabetic, Validate,
+    };
+
+    #[derive(FromForm, Validate)]
+    struct Example {
+        #[vate(StringAlphabetic)]
+        a: String,
+    }
+
+    #[post("/", data = "<example>")]
+    fn create(example: Validated<Form<Example>>) -> String {
+        example.into_inner().into_inner().a
+    }
+
+    #[catch(422)]
+    fn unprocessable(request: &Request) -> String {
+        problem_details(request).unwrap().errors[0].message.clone()
+    }
+
+    fn client() -> Client {
+        let rocket = rocket::build()
+            .manage(())
+            .mount("/", routes![create])
+            .register("/", catchers![unprocessable]);
+        Client::tracked(rocket).unwrap()
+    }
+
+    #[test]
+    fn validated() {
+        let client = client();
+
+        let response = client
+            .post("/")
+            .header(ContentType::Form)
+            .body("a=a")
+            .dispatch();
+
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_string().unwrap(), "a");
+    }
+
+    #[test]
+    fn validated_invalid() {
+        let client = client();
+
+        let response = client
+            .post("/")
+            .header(ContentType::Form)
+            .body("a=0")
+            .dispatch();
+
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+        assert_eq!(
+            response.into_string().unwrap(),
+            "contains non-alphabetic characters"
+        );
+    }
+}
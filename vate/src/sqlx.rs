@@ -0,0 +1,81 @@
+use ::sqlx::{ColumnIndex, Database, Decode, Encode, Executor, IntoArguments, Pool, Type};
+
+use crate::UniquenessChecker;
+
+/// Checks if values are taken with a query, which is passed the value as its only argument
+/// and returns whether it is taken, such as
+/// `SELECT EXISTS(SELECT 1 FROM users WHERE username = $1)`.
+pub struct SqlxUniquenessChecker<'a, DB: Database> {
+    /// The pool the query is executed on.
+    pub pool: &'a Pool<DB>,
+    /// The query that checks if a value is taken.
+    pub query: &'a str,
+}
+
+impl<DB> UniquenessChecker<::sqlx::Error> for SqlxUniquenessChecker<'_, DB>
+where
+    DB: Database,
+    for<'q> DB::Arguments<'q>: IntoArguments<'q, DB>,
+    for<'q> &'q str: Encode<'q, DB> + Type<DB>,
+    for<'r> bool: Decode<'r, DB> + Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+{
+    async fn is_taken(&self, value: &str) -> Result<bool, ::sqlx::Error> {
+        let Self { pool, query } = *self;
+        ::sqlx::query_scalar::<DB, bool>(query)
+            .bind(value)
+            .fetch_one(pool)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+    use vate::{
+        path, sqlx::SqlxUniquenessChecker, Accessor, CheckedValues, Everything, Report,
+        StringUnique, Validate,
+    };
+
+    #[tokio::test]
+    async fn sqlx_uniqueness_checker() {
+        #[derive(Validate)]
+        #[vate(data = CheckedValues)]
+        struct Example {
+            #[vate(StringUnique(data))]
+            a: String,
+            #[vate(StringUnique(data))]
+            b: String,
+        }
+
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("CREATE TABLE users (username TEXT NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO users (username) VALUES ('gintoki')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let example = Example {
+            a: String::from("gintoki"),
+            b: String::from("kagura"),
+        };
+
+        let checker = SqlxUniquenessChecker {
+            pool: &pool,
+            query: "SELECT EXISTS(SELECT 1 FROM users WHERE username = $1)",
+        };
+        let data = CheckedValues::check(&checker, [&example.a, &example.b])
+            .await
+            .unwrap();
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&data, &mut report);
+
+        assert!(report.is_invalid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_valid_at_path(path!(example.b)).unwrap());
+    }
+}
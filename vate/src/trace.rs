@@ -0,0 +1,203 @@
+use ::tracing::{debug, debug_span, Span};
+
+use crate::{Accessor, Report};
+
+/// Create the span of a derived `Validate::validate`, named by the type of the target and the
+/// accessor of its report. The spans of nested targets make up the path to the reports
+/// validated within them.
+pub(crate) fn validate_span(type_name: &'static str, accessor: &Accessor) -> Span {
+    debug_span!("validate", r#type = type_name, accessor = %accessor)
+}
+
+/// Create the span of an iterated target, named by the accessor of its report.
+pub(crate) fn iterate_span(accessor: &Accessor) -> Span {
+    debug_span!("iterate", accessor = %accessor)
+}
+
+/// Emit an event if a child report applied by a collector is invalid or erroneous.
+/// The message of the report is the `reason` field, since `message` is the event message.
+pub(crate) fn child<E>(child: &Report<E>) {
+    match child.get_validity() {
+        Ok(true) => {}
+        Ok(false) => debug!(
+            accessor = %child.get_accessor(),
+            reason = %child.get_message(),
+            "invalid"
+        ),
+        Err(_) => debug!(
+            accessor = %child.get_accessor(),
+            reason = %child.get_message(),
+            "error"
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fmt::Debug,
+        sync::{Arc, Mutex},
+    };
+
+    use tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+        Event, Metadata, Subscriber,
+    };
+    use vate::{Accessor, Collector, Everything, Exit, Nested, Report, StringAlphabetic, Validate};
+
+    /// The fields of a span or event, formatted as `name=value`.
+    #[derive(Default)]
+    struct Fields(Vec<String>);
+
+    impl Visit for Fields {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0.push(format!("{}={value:?}", field.name()));
+        }
+    }
+
+    /// An event, along with the fields of the spans it was emitted in.
+    struct CapturedEvent {
+        spans: Vec<String>,
+        fields: Vec<String>,
+    }
+
+    /// The spans, entered spans, and events captured by `Capture`.
+    #[derive(Default)]
+    struct Captured {
+        spans: Vec<Vec<String>>,
+        stack: Vec<usize>,
+        events: Vec<CapturedEvent>,
+    }
+
+    /// Captures events along with the fields of the spans they were emitted in.
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Captured>>);
+
+    impl Subscriber for Capture {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+            let mut fields = Fields::default();
+            attributes.record(&mut fields);
+            let mut captured = self.0.lock().unwrap();
+            captured.spans.push(fields.0);
+            Id::from_u64(captured.spans.len() as u64)
+        }
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields::default();
+            event.record(&mut fields);
+            let mut captured = self.0.lock().unwrap();
+            let spans = captured
+                .stack
+                .iter()
+                .flat_map(|&index| captured.spans[index].clone())
+                .collect();
+            captured.events.push(CapturedEvent {
+                spans,
+                fields: fields.0,
+            });
+        }
+        fn enter(&self, span: &Id) {
+            let index = span.into_u64() as usize - 1;
+            self.0.lock().unwrap().stack.push(index);
+        }
+        fn exit(&self, _span: &Id) {
+            self.0.lock().unwrap().stack.pop();
+        }
+    }
+
+    #[test]
+    fn trace() {
+        #[derive(Validate)]
+        struct Example1 {
+            #[vate(Nested)]
+            example2: Example2,
+        }
+
+        #[derive(Validate)]
+        struct Example2 {
+            #[vate(StringAlphabetic)]
+            a: String,
+            #[vate(StringAlphabetic)]
+            b: String,
+        }
+
+        let example1 = Example1 {
+            example2: Example2 {
+                a: String::from("0"),
+                b: String::from("b"),
+            },
+        };
+
+        let capture = Capture::default();
+        tracing::subscriber::with_default(capture.clone(), || {
+            let mut report = Report::new(Accessor::Root("example1"));
+            let _ = example1.validate::<Everything>(&(), &mut report);
+        });
+
+        let events = &capture.0.lock().unwrap().events;
+        assert_eq!(events.len(), 2);
+
+        let CapturedEvent { spans, fields } = &events[0];
+        assert_eq!(
+            spans,
+            &[
+                "type=\"Example1\"",
+                "accessor=example1",
+                "type=\"Example2\"",
+                "accessor=.example2"
+            ]
+        );
+        assert_eq!(
+            fields,
+            &[
+                "message=invalid",
+                "accessor=.a",
+                "reason=contains non-alphabetic characters"
+            ]
+        );
+
+        let CapturedEvent { spans, fields } = &events[1];
+        assert_eq!(spans, &["type=\"Example1\"", "accessor=example1"]);
+        assert_eq!(
+            fields,
+            &["message=invalid", "accessor=.example2", "reason="]
+        );
+    }
+
+    #[test]
+    fn trace_custom_collector() {
+        /// Collects nothing.
+        struct Nothing;
+
+        impl<E> Collector<E> for Nothing {
+            fn collect(_parent: &mut Report<E>, _child: Report<E>) -> Result<(), Exit<E>> {
+                Ok(())
+            }
+        }
+
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringAlphabetic)]
+            a: String,
+        }
+
+        let example = Example {
+            a: String::from("0"),
+        };
+
+        let capture = Capture::default();
+        tracing::subscriber::with_default(capture.clone(), || {
+            let mut report = Report::new(Accessor::Root("example"));
+            let _ = example.validate::<Nothing>(&(), &mut report);
+        });
+
+        let events = &capture.0.lock().unwrap().events;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].fields[1], "accessor=.a");
+    }
+}
@@ -0,0 +1,168 @@
+use std::ops::Deref;
+
+use crate::{Accessor, Collector, Exit, Report, Validate};
+
+/// A target that has been successfully validated.
+/// A `Valid<T>` can only be constructed by validating a `T`, so functions that
+/// require a `Valid<T>` can skip re-validating it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
+pub struct Valid<T>(T);
+
+impl<T: Validate> Valid<T> {
+    /// Validate the target, wrapping it if the report is valid after validating.
+    /// Otherwise, the target is given back. If validation exits with an error,
+    /// the error is set as the validity of the report.
+    pub fn new<C: Collector<T::Error>>(
+        target: T,
+        data: &T::Data,
+        report: &mut Report<T::Error>,
+    ) -> Result<Self, T> {
+        if let Err(Exit::WithError(error)) = target.validate::<C>(data, report) {
+            report.set_error(error);
+        }
+
+        if report.is_valid() {
+            Ok(Self(target))
+        } else {
+            Err(target)
+        }
+    }
+}
+
+impl<T> Valid<T> {
+    /// Unwrap the validated target.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Valid<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> AsRef<T> for Valid<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+/// Deserializes the target, then validates it with the default data.
+/// If the target is not valid, deserialization fails with the paths and messages of the
+/// invalid and erroneous reports. This enforces validation even where only a deserializer,
+/// such as `serde_json::from_str`, is called.
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Valid<T>
+where
+    T: serde::Deserialize<'de> + Validate,
+    T::Data: Default,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let target = T::deserialize(deserializer)?;
+        target
+            .into_valid::<crate::InvalidsAndErrors>(Accessor::Root("value"), &T::Data::default())
+            .map_err(|report| {
+                let summary = crate::MapReport::from(&report).summary();
+                serde::de::Error::custom(format!("invalid value: {summary}"))
+            })
+    }
+}
+
+/// Extension methods for validatable types.
+pub trait ValidateExt: Validate + Sized {
+    /// Validate the target into a `Valid`, returning a report with the given
+    /// accessor as the error if the target is not valid.
+    fn into_valid<C: Collector<Self::Error>>(
+        self,
+        accessor: Accessor,
+        data: &Self::Data,
+    ) -> Result<Valid<Self>, Report<Self::Error>>;
+}
+
+impl<T: Validate> ValidateExt for T {
+    fn into_valid<C: Collector<Self::Error>>(
+        self,
+        accessor: Accessor,
+        data: &Self::Data,
+    ) -> Result<Valid<Self>, Report<Self::Error>> {
+        let mut report = Report::new(accessor);
+        Valid::new::<C>(self, data, &mut report).map_err(|_| report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vate::{Accessor, Everything, Report, StringAlphabetic, Valid, Validate, ValidateExt};
+
+    #[derive(Debug, Validate)]
+    struct Example {
+        #[vate(StringAlphabetic)]
+        a: String,
+    }
+
+    #[test]
+    fn valid() {
+        let example = Example {
+            a: String::from("abc"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let valid = Valid::new::<Everything>(example, &(), &mut report)
+            .ok()
+            .unwrap();
+
+        assert_eq!(valid.a, "abc");
+    }
+
+    #[test]
+    fn invalid() {
+        let example = Example {
+            a: String::from("0"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let example = Valid::new::<Everything>(example, &(), &mut report).unwrap_err();
+
+        assert_eq!(example.a, "0");
+        assert!(report.is_invalid());
+    }
+
+    #[test]
+    fn into_valid() {
+        let example = Example {
+            a: String::from("0"),
+        };
+
+        let report = example
+            .into_valid::<Everything>(Accessor::Root("example"), &())
+            .unwrap_err();
+
+        assert!(report.is_invalid());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize() {
+        use serde::de::{value::Error, value::MapDeserializer, Deserialize};
+
+        #[derive(serde::Deserialize, Validate)]
+        struct Example {
+            #[vate(StringAlphabetic)]
+            a: String,
+        }
+
+        let deserializer = MapDeserializer::<_, Error>::new([("a", "abc")].into_iter());
+        let valid = Valid::<Example>::deserialize(deserializer).unwrap();
+        assert_eq!(valid.a, "abc");
+
+        let deserializer = MapDeserializer::<_, Error>::new([("a", "0")].into_iter());
+        let error = Valid::<Example>::deserialize(deserializer).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "invalid value: a contains non-alphabetic characters"
+        );
+    }
+}
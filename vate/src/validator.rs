@@ -0,0 +1,393 @@
+use std::borrow::Cow;
+
+use ::validator::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+use crate::{Accessor, Collector, Exit, Report, Validator};
+
+/// The field `validator` uses for errors that belong to a struct instead of one of its fields.
+const SCHEMA_FIELD: &str = "__all__";
+
+/// Converts a report into `validator` errors, to ease migrating between the crates.
+/// Reports accessed by fields and keys become fields, reports accessed by indices become lists,
+/// and the messages of reports with children are added to the `__all__` field. Invalid reports
+/// have the code `invalid`, and erroneous reports have the code `error`. Reports that become
+/// the same field, such as `Field("a")` and `Key("a")`, are merged.
+impl<E> From<&Report<E>> for ValidationErrors {
+    fn from(report: &Report<E>) -> Self {
+        let mut errors = ValidationErrors::new();
+
+        if let Some(error) = validation_error(report) {
+            push_error(&mut errors, Cow::Borrowed(SCHEMA_FIELD), error);
+        }
+
+        for child in report.children().filter(|child| is_any_not_valid(child)) {
+            let field = match child.get_accessor() {
+                Accessor::Root(field) | Accessor::Field(field) => Cow::Borrowed(*field),
+                Accessor::Index(index) => Cow::Owned(index.to_string()),
+                Accessor::Key(key) => Cow::Owned(key.clone()),
+                Accessor::IntegerKey(key) => Cow::Owned(key.to_string()),
+            };
+
+            if child.children().next().is_none() {
+                if let Some(error) = validation_error(child) {
+                    push_error(&mut errors, field, error);
+                }
+            } else if child
+                .children()
+                .all(|grandchild| matches!(grandchild.get_accessor(), Accessor::Index(_)))
+                && validation_error(child).is_none()
+            {
+                let list = child
+                    .children()
+                    .filter(|grandchild| is_any_not_valid(grandchild))
+                    .filter_map(|grandchild| match grandchild.get_accessor() {
+                        Accessor::Index(index) => Some((*index, Box::new(grandchild.into()))),
+                        _ => None,
+                    })
+                    .collect();
+                insert_errors(&mut errors, field, ValidationErrorsKind::List(list));
+            } else {
+                let nested = Box::new(ValidationErrors::from(child));
+                insert_errors(&mut errors, field, ValidationErrorsKind::Struct(nested));
+            }
+        }
+
+        errors
+    }
+}
+
+/// Check if the report or any of its descendants is not valid.
+fn is_any_not_valid<E>(report: &Report<E>) -> bool {
+    report.iter().any(|report| !report.is_valid())
+}
+
+/// Create the `validator` error of a report, if it is not valid and has a message.
+fn validation_error<E>(report: &Report<E>) -> Option<ValidationError> {
+    if report.is_valid() || report.get_message().is_empty() {
+        return None;
+    }
+    let code = if report.is_error() {
+        "error"
+    } else {
+        "invalid"
+    };
+    Some(ValidationError::new(code).with_message(Cow::Owned(report.get_message().clone())))
+}
+
+/// Push a field error.
+fn push_error(errors: &mut ValidationErrors, field: Cow<'static, str>, error: ValidationError) {
+    insert_errors(errors, field, ValidationErrorsKind::Field(vec![error]));
+}
+
+/// Insert the errors of a field, merging them with the errors already at the field, since
+/// reports with different accessors can become the same field, such as `Field("a")` and
+/// `Key("a")`, or `Index(0)` and `Key("0")`.
+fn insert_errors(
+    errors: &mut ValidationErrors,
+    field: Cow<'static, str>,
+    kind: ValidationErrorsKind,
+) {
+    let kind = match errors.0.remove(&field) {
+        Some(existing) => merge_kinds(existing, kind),
+        None => kind,
+    };
+    errors.0.insert(field, kind);
+}
+
+/// Merge the errors of two kinds. Kinds that differ are merged as structs.
+fn merge_kinds(kind: ValidationErrorsKind, other: ValidationErrorsKind) -> ValidationErrorsKind {
+    match (kind, other) {
+        (ValidationErrorsKind::Field(mut field_errors), ValidationErrorsKind::Field(other)) => {
+            field_errors.extend(other);
+            ValidationErrorsKind::Field(field_errors)
+        }
+        (ValidationErrorsKind::List(mut list), ValidationErrorsKind::List(other)) => {
+            for (index, other) in other {
+                let nested = match list.remove(&index) {
+                    Some(mut nested) => {
+                        merge_errors(&mut nested, *other);
+                        nested
+                    }
+                    None => other,
+                };
+                list.insert(index, nested);
+            }
+            ValidationErrorsKind::List(list)
+        }
+        (kind, other) => {
+            let mut nested = into_struct(kind);
+            merge_errors(&mut nested, into_struct(other));
+            ValidationErrorsKind::Struct(Box::new(nested))
+        }
+    }
+}
+
+/// Merge other errors into errors.
+fn merge_errors(errors: &mut ValidationErrors, other: ValidationErrors) {
+    for (field, kind) in other.into_errors() {
+        insert_errors(errors, field, kind);
+    }
+}
+
+/// Convert the errors of a kind into the errors of a struct. Field errors become errors of
+/// the `__all__` field, and the elements of lists become fields named by their indices.
+fn into_struct(kind: ValidationErrorsKind) -> ValidationErrors {
+    match kind {
+        ValidationErrorsKind::Field(field_errors) => {
+            let mut errors = ValidationErrors::new();
+            insert_errors(
+                &mut errors,
+                Cow::Borrowed(SCHEMA_FIELD),
+                ValidationErrorsKind::Field(field_errors),
+            );
+            errors
+        }
+        ValidationErrorsKind::Struct(nested) => *nested,
+        ValidationErrorsKind::List(list) => {
+            let mut errors = ValidationErrors::new();
+            for (index, nested) in list {
+                insert_errors(
+                    &mut errors,
+                    Cow::Owned(index.to_string()),
+                    ValidationErrorsKind::Struct(nested),
+                );
+            }
+            errors
+        }
+    }
+}
+
+/// Validates a target that implements `validator::Validate`, converting its errors into
+/// child reports. Useful for nesting types that have not been migrated from `validator` yet.
+pub struct ValidatorNested;
+
+impl<T, D, E> Validator<T, D, E> for ValidatorNested
+where
+    T: ::validator::Validate,
+{
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let mut child_report = Report::new(accessor);
+
+        let child_result = match target.validate() {
+            Ok(()) => Ok(()),
+            Err(errors) => collect_errors::<C, E>(errors, &mut child_report),
+        };
+
+        let parent_result = C::apply(parent_report, child_report);
+        child_result?;
+        parent_result
+    }
+}
+
+/// Collect `validator` errors into child reports.
+fn collect_errors<C: Collector<E>, E>(
+    errors: ValidationErrors,
+    parent_report: &mut Report<E>,
+) -> Result<(), Exit<E>> {
+    for (field, kind) in errors.into_errors() {
+        let accessor = match field {
+            Cow::Borrowed(field) => Accessor::Field(field),
+            Cow::Owned(field) => Accessor::Key(field),
+        };
+
+        let mut child_report = Report::new(accessor);
+
+        let child_result = match kind {
+            ValidationErrorsKind::Field(field_errors) => {
+                let messages = field_errors
+                    .iter()
+                    .map(|error| error.message.as_ref().unwrap_or(&error.code).as_ref())
+                    .collect::<Vec<_>>();
+                child_report.set_invalid();
+                child_report.set_message(messages.join("; "));
+                Ok(())
+            }
+            ValidationErrorsKind::Struct(nested) => {
+                collect_errors::<C, E>(*nested, &mut child_report)
+            }
+            ValidationErrorsKind::List(list) => list.into_iter().try_for_each(|(index, nested)| {
+                let mut element_report = Report::new(Accessor::Index(index));
+                let element_result = collect_errors::<C, E>(*nested, &mut element_report);
+                let parent_result = C::apply(&mut child_report, element_report);
+                element_result?;
+                parent_result
+            }),
+        };
+
+        let parent_result = C::apply(parent_report, child_report);
+        child_result?;
+        parent_result?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use validator::{ValidationError, ValidationErrors, ValidationErrorsKind};
+    use vate::{
+        path, validator::ValidatorNested, Accessor, CollectionIterate, Everything, IteratorIndexed,
+        Nested, Report, StringAlphabetic, Validate, Validator,
+    };
+
+    #[test]
+    fn into_validation_errors() {
+        #[derive(Validate)]
+        struct Example1 {
+            #[vate(Nested)]
+            example2: Example2,
+            #[vate(CollectionIterate(IteratorIndexed(Nested)))]
+            v: Vec<Example2>,
+        }
+
+        #[derive(Validate)]
+        struct Example2 {
+            #[vate(StringAlphabetic)]
+            a: String,
+        }
+
+        let example1 = Example1 {
+            example2: Example2 {
+                a: String::from("0"),
+            },
+            v: vec![
+                Example2 {
+                    a: String::from("a"),
+                },
+                Example2 {
+                    a: String::from("1"),
+                },
+            ],
+        };
+
+        let mut report = Report::new(Accessor::Root("example1"));
+        let _ = example1.validate::<Everything>(&(), &mut report);
+
+        let errors = ValidationErrors::from(&report);
+
+        let ValidationErrorsKind::Struct(example2) = &errors.errors()["example2"] else {
+            panic!("expected struct errors");
+        };
+        assert_eq!(example2.field_errors()["a"][0].code, "invalid");
+
+        let ValidationErrorsKind::List(v) = &errors.errors()["v"] else {
+            panic!("expected list errors");
+        };
+        assert_eq!(v.keys().copied().collect::<Vec<_>>(), [1]);
+    }
+
+    #[test]
+    fn into_validation_errors_collision() {
+        #[derive(Validate)]
+        struct Example1 {
+            #[vate(StringAlphabetic)]
+            a: String,
+            #[vate(Nested)]
+            example2: Example2,
+        }
+
+        #[derive(Validate)]
+        struct Example2 {
+            #[vate(StringAlphabetic)]
+            b: String,
+        }
+
+        let example1 = Example1 {
+            a: String::from("0"),
+            example2: Example2 {
+                b: String::from("1"),
+            },
+        };
+
+        let mut report = Report::new(Accessor::Root("example1"));
+        let _ = example1.validate::<Everything>(&(), &mut report);
+
+        // A map validated into the same report, keyed by the names of the fields.
+        let map = BTreeMap::from([
+            (String::from("a"), String::from("2")),
+            (String::from("example2"), String::from("3")),
+        ]);
+        for (key, value) in &map {
+            let _ = StringAlphabetic.run::<Everything>(
+                Accessor::Key(key.clone()),
+                value,
+                &(),
+                &mut report,
+            );
+        }
+
+        let errors = ValidationErrors::from(&report);
+
+        assert_eq!(errors.field_errors()["a"].len(), 2);
+        let ValidationErrorsKind::Struct(example2) = &errors.errors()["example2"] else {
+            panic!("expected struct errors");
+        };
+        assert_eq!(example2.field_errors()["b"][0].code, "invalid");
+        assert_eq!(example2.field_errors()["__all__"][0].code, "invalid");
+
+        let mut report = Report::new(Accessor::Root("examples"));
+        let examples = [Example2 {
+            b: String::from("4"),
+        }];
+        let _ = vate::validate_iter::<_, Everything>(examples, &(), &mut report);
+        let _ = StringAlphabetic.run::<Everything>(
+            Accessor::Key(String::from("0")),
+            &"5",
+            &(),
+            &mut report,
+        );
+
+        let errors = ValidationErrors::from(&report);
+
+        let ValidationErrorsKind::Struct(element) = &errors.errors()["0"] else {
+            panic!("expected struct errors");
+        };
+        assert_eq!(element.field_errors()["b"][0].code, "invalid");
+        assert_eq!(element.field_errors()["__all__"][0].code, "invalid");
+    }
+
+    #[test]
+    fn validator_nested() {
+        struct Legacy {
+            a: String,
+        }
+
+        impl validator::Validate for Legacy {
+            fn validate(&self) -> Result<(), ValidationErrors> {
+                let mut errors = ValidationErrors::new();
+                if self.a.len() < 2 {
+                    errors.add("a", ValidationError::new("length"));
+                }
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(errors)
+                }
+            }
+        }
+
+        #[derive(Validate)]
+        struct Example {
+            #[vate(ValidatorNested)]
+            legacy: Legacy,
+        }
+
+        let example = Example {
+            legacy: Legacy {
+                a: String::from("a"),
+            },
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_invalid_at_path(path!(example.legacy.a)).unwrap());
+    }
+}
@@ -0,0 +1,186 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use crate::{Accessor, Collector, Exit, FromData, Report, Validator};
+
+/// Limits the work of a validation, protecting against maliciously deep or large targets.
+/// The budget is carried in the data of a validation, and spent by `Budgeted` validators.
+/// A budget is spent by one validation, so create a new budget for each validation.
+pub struct ValidationBudget {
+    /// The maximum depth of nested `Budgeted` validators.
+    max_depth: usize,
+    /// The number of `Budgeted` validators that can still run.
+    remaining: AtomicUsize,
+    /// The current depth of nested `Budgeted` validators.
+    depth: AtomicUsize,
+    /// Whether the budget was exceeded.
+    exceeded: AtomicBool,
+}
+
+impl ValidationBudget {
+    /// Create a budget that allows `max_runs` runs of `Budgeted` validators, nested at most
+    /// `max_depth` deep.
+    pub const fn new(max_runs: usize, max_depth: usize) -> Self {
+        Self {
+            max_depth,
+            remaining: AtomicUsize::new(max_runs),
+            depth: AtomicUsize::new(0),
+            exceeded: AtomicBool::new(false),
+        }
+    }
+    /// Get the number of `Budgeted` validators that can still run.
+    pub fn remaining(&self) -> usize {
+        self.remaining.load(Ordering::Relaxed)
+    }
+    /// Check if the budget was exceeded, which aborted the validation.
+    pub fn is_exceeded(&self) -> bool {
+        self.exceeded.load(Ordering::Relaxed)
+    }
+    /// Spend a run and enter a level of depth, returning a message if the budget is exceeded.
+    fn enter(&self) -> Option<String> {
+        let spent =
+            self.remaining
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |remaining| {
+                    remaining.checked_sub(1)
+                });
+        if spent.is_err() {
+            return Some(String::from("exceeds the validation budget"));
+        }
+
+        let depth = self.depth.fetch_add(1, Ordering::Relaxed) + 1;
+        if depth > self.max_depth {
+            self.exit();
+            return Some(format!(
+                "is nested deeper than the validation budget of {}",
+                self.max_depth
+            ));
+        }
+
+        None
+    }
+    /// Leave a level of depth.
+    fn exit(&self) {
+        self.depth.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+pub struct Budgeted<V>(pub V);
+
+impl<T, D, E, V> Validator<T, D, E> for Budgeted<V>
+where
+    ValidationBudget: FromData<D>,
+    V: Validator<T, D, E>,
+{
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(validator) = self;
+        let budget = ValidationBudget::from_data(data);
+
+        match budget.enter() {
+            None => {
+                let result = validator.run::<C>(accessor, target, data, parent_report);
+                budget.exit();
+                result
+            }
+            Some(message) => {
+                budget.exceeded.store(true, Ordering::Relaxed);
+                let mut child_report = Report::new(accessor);
+                child_report.set_invalid();
+                child_report.set_message(message);
+                let _ = C::apply(parent_report, child_report);
+                Err(Exit::Gracefully)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vate::{
+        path, Accessor, Budgeted, CollectionIterate, Everything, IteratorIndexed, Nested, Report,
+        StringAlphabetic, Validate, ValidationBudget,
+    };
+
+    #[derive(Validate)]
+    #[vate(data = ValidationBudget)]
+    struct Node {
+        #[vate(StringAlphabetic)]
+        name: String,
+        #[vate(CollectionIterate(IteratorIndexed(Budgeted(Nested))))]
+        children: Vec<Node>,
+    }
+
+    impl Node {
+        fn new(children: Vec<Node>) -> Self {
+            Self {
+                name: String::from("a"),
+                children,
+            }
+        }
+    }
+
+    #[test]
+    fn validation_budget_depth() {
+        let mut node = Node::new(Vec::new());
+        for _ in 0..5 {
+            node = Node::new(vec![node]);
+        }
+
+        let budget = ValidationBudget::new(100, 5);
+        let mut report = Report::new(Accessor::Root("node"));
+        let _ = node.validate::<Everything>(&budget, &mut report);
+
+        assert!(!budget.is_exceeded());
+        assert!(report.is_valid());
+
+        let node = Node::new(vec![node]);
+
+        let budget = ValidationBudget::new(100, 5);
+        let mut report = Report::new(Accessor::Root("node"));
+        let result = node.validate::<Everything>(&budget, &mut report);
+
+        assert!(result.is_err());
+        assert!(budget.is_exceeded());
+        assert_eq!(
+            report
+                .get_at_path(path!(
+                    node.children[0].children[0].children[0].children[0].children[0].children[0]
+                ))
+                .unwrap()
+                .get_message(),
+            "is nested deeper than the validation budget of 5"
+        );
+    }
+
+    #[test]
+    fn validation_budget_runs() {
+        let node = Node::new((0..10).map(|_| Node::new(Vec::new())).collect());
+
+        let budget = ValidationBudget::new(11, 1);
+        let mut report = Report::new(Accessor::Root("node"));
+        let _ = node.validate::<Everything>(&budget, &mut report);
+
+        assert!(!budget.is_exceeded());
+        assert_eq!(budget.remaining(), 1);
+
+        let budget = ValidationBudget::new(3, 1);
+        let mut report = Report::new(Accessor::Root("node"));
+        let result = node.validate::<Everything>(&budget, &mut report);
+
+        assert!(result.is_err());
+        assert!(budget.is_exceeded());
+        assert!(report.is_valid_at_path(path!(node.children[2])).unwrap());
+        assert_eq!(
+            report
+                .get_at_path(path!(node.children[3]))
+                .unwrap()
+                .get_message(),
+            "exceeds the validation budget"
+        );
+        assert!(report.get_at_path(path!(node.children[4])).is_none());
+    }
+}
@@ -19,6 +19,12 @@ where
         validator1.run::<C>(accessor.clone(), target, data, parent_report)?;
         validator2.run::<C>(accessor, target, data, parent_report)
     }
+
+    fn passes(&self, target: &T, data: &D) -> bool {
+        let Self(validator1, validator2) = self;
+
+        validator1.passes(target, data) && validator2.passes(target, data)
+    }
 }
 
 // Note: This macro's name is `UpperCamelCase`, which doesn't conform with typical macro naming conventions.
@@ -32,6 +38,49 @@ macro_rules! Bundle {
         $crate::Bundle2($a, $b)
     };
     ($a:expr, $($rest:tt)*) => {
-        $crate::Bundle2($a, Bundle!($($rest)*))
+        $crate::Bundle2($a, $crate::Bundle!($($rest)*))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use vate::{
+        path, Accessor, Bundle2, Everything, Report, StringAlphanumeric, StringLengthRange,
+        Validate,
     };
+
+    const USERNAME_RULES: Bundle2<StringAlphanumeric, StringLengthRange> = Bundle!(
+        StringAlphanumeric,
+        StringLengthRange::Chars { min: 4, max: 20 }
+    );
+
+    #[test]
+    fn bundle_const() {
+        #[derive(Validate)]
+        struct Example1 {
+            #[vate(USERNAME_RULES)]
+            username: String,
+        }
+
+        #[derive(Validate)]
+        struct Example2 {
+            #[vate(USERNAME_RULES)]
+            username: String,
+        }
+
+        let example1 = Example1 {
+            username: String::from("vate"),
+        };
+        let example2 = Example2 {
+            username: String::from("v@te"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example1"));
+        let _ = example1.validate::<Everything>(&(), &mut report);
+        assert!(report.is_valid_at_path(path!(example1.username)).unwrap());
+
+        let mut report = Report::new(Accessor::Root("example2"));
+        let _ = example2.validate::<Everything>(&(), &mut report);
+        assert!(report.is_invalid_at_path(path!(example2.username)).unwrap());
+    }
 }
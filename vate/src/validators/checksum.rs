@@ -0,0 +1,268 @@
+use crate::{Accessor, Collector, Exit, Report, Validator};
+
+/// The multiplication table of the dihedral group D5, used by the Verhoeff algorithm.
+const VERHOEFF_MULTIPLICATION: [[u8; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+/// The permutation table of the Verhoeff algorithm.
+const VERHOEFF_PERMUTATION: [[u8; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
+    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+];
+
+/// The weakly totally anti-symmetric quasigroup of order 10, used by the Damm algorithm.
+const DAMM_QUASIGROUP: [[u8; 10]; 10] = [
+    [0, 3, 1, 7, 5, 9, 8, 6, 4, 2],
+    [7, 0, 9, 2, 1, 5, 4, 8, 6, 3],
+    [4, 2, 0, 6, 8, 7, 1, 3, 5, 9],
+    [1, 7, 5, 0, 9, 8, 3, 4, 2, 6],
+    [6, 1, 2, 3, 0, 4, 5, 9, 7, 8],
+    [3, 6, 7, 4, 2, 0, 9, 5, 8, 1],
+    [5, 8, 6, 9, 7, 2, 0, 1, 3, 4],
+    [8, 9, 4, 5, 3, 6, 2, 0, 1, 7],
+    [9, 4, 3, 8, 6, 1, 7, 2, 0, 5],
+    [2, 5, 8, 1, 4, 3, 6, 7, 9, 0],
+];
+
+/// Convert a string of ASCII digits into their values, or `None` if it is empty or contains
+/// a character that is not a digit.
+pub(crate) fn digits(target: &str) -> Option<Vec<u8>> {
+    if target.is_empty() {
+        return None;
+    }
+    target
+        .bytes()
+        .map(|byte| byte.is_ascii_digit().then(|| byte - b'0'))
+        .collect()
+}
+
+/// Check the trailing check digit of digits with the Luhn algorithm.
+pub(crate) fn luhn(digits: &[u8]) -> bool {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, &digit)| {
+            let digit = u32::from(digit);
+            if index % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                digit
+            }
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+/// Check the trailing check digit of digits with the Verhoeff algorithm.
+fn verhoeff(digits: &[u8]) -> bool {
+    let check = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .fold(0, |check, (index, &digit)| {
+            let permuted = VERHOEFF_PERMUTATION[index % 8][usize::from(digit)];
+            VERHOEFF_MULTIPLICATION[usize::from(check)][usize::from(permuted)]
+        });
+    check == 0
+}
+
+/// Check the trailing check digit of digits with the Damm algorithm.
+fn damm(digits: &[u8]) -> bool {
+    let interim = digits.iter().fold(0, |interim, &digit| {
+        DAMM_QUASIGROUP[usize::from(interim)][usize::from(digit)]
+    });
+    interim == 0
+}
+
+/// Compute the CRC-32 (IEEE 802.3) checksum of bytes.
+fn crc32(bytes: &[u8]) -> u32 {
+    let crc = bytes.iter().fold(u32::MAX, |crc, &byte| {
+        (0..8).fold(crc ^ u32::from(byte), |crc, _| {
+            if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            }
+        })
+    });
+    !crc
+}
+
+/// Run a check digit algorithm on a digit string.
+fn run_check_digit<C: Collector<E>, E>(
+    accessor: Accessor,
+    target: &str,
+    algorithm: &str,
+    check: fn(&[u8]) -> bool,
+    parent_report: &mut Report<E>,
+) -> Result<(), Exit<E>> {
+    let mut child_report = Report::new(accessor);
+
+    match digits(target) {
+        Some(digits) if check(&digits) => child_report.set_valid(),
+        Some(_) => {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "is \"{target}\", which has an invalid {algorithm} check digit"
+            ));
+        }
+        None => {
+            child_report.set_invalid();
+            child_report.set_message(format!("is \"{target}\", which is not a digit string"));
+        }
+    }
+
+    C::apply(parent_report, child_report)
+}
+
+pub struct StringLuhn;
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringLuhn {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        run_check_digit::<C, E>(accessor, target.as_ref(), "Luhn", luhn, parent_report)
+    }
+}
+
+pub struct StringVerhoeff;
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringVerhoeff {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        run_check_digit::<C, E>(
+            accessor,
+            target.as_ref(),
+            "Verhoeff",
+            verhoeff,
+            parent_report,
+        )
+    }
+}
+
+pub struct StringDamm;
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringDamm {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        run_check_digit::<C, E>(accessor, target.as_ref(), "Damm", damm, parent_report)
+    }
+}
+
+pub struct BytesCrc32Matches(pub u32);
+
+impl<T: AsRef<[u8]>, D, E> Validator<T, D, E> for BytesCrc32Matches {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(expected) = *self;
+
+        let mut child_report = Report::new(accessor);
+
+        let checksum = crc32(target.as_ref());
+        if checksum == expected {
+            child_report.set_valid();
+        } else {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "has the CRC-32 checksum {checksum:08x}, which is not {expected:08x}"
+            ));
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vate::{
+        path, Accessor, BytesCrc32Matches, Everything, Report, StringDamm, StringLuhn,
+        StringVerhoeff, Validate,
+    };
+
+    #[test]
+    fn checksum() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringLuhn)]
+            a: String,
+            #[vate(StringLuhn)]
+            b: String,
+            #[vate(StringVerhoeff)]
+            c: String,
+            #[vate(StringVerhoeff)]
+            d: String,
+            #[vate(StringDamm)]
+            e: String,
+            #[vate(StringDamm)]
+            f: String,
+            #[vate(BytesCrc32Matches(0xCBF43926))]
+            g: Vec<u8>,
+            #[vate(BytesCrc32Matches(0xCBF43926))]
+            h: Vec<u8>,
+        }
+
+        let example = Example {
+            a: String::from("79927398713"),
+            b: String::from("7992739871a"),
+            c: String::from("2363"),
+            d: String::from("2364"),
+            e: String::from("5724"),
+            f: String::from("5727"),
+            g: b"123456789".to_vec(),
+            h: b"12345678".to_vec(),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.b)).unwrap());
+        assert!(report.is_valid_at_path(path!(example.c)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.d)).unwrap());
+        assert!(report.is_valid_at_path(path!(example.e)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.f)).unwrap());
+        assert!(report.is_valid_at_path(path!(example.g)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.h)).unwrap());
+    }
+}
@@ -25,40 +25,47 @@ macro_rules! Compare {
         ::vate::CompareLessThan(::std::borrow::Cow::Borrowed(&$value))
     };
     ( < $value:expr) => {
-        ::vate::CompareLessThan(::std::borrow::Cow::Owned($value))
+        ::vate::CompareLessThan(::vate::__private::compare_owned($value))
     };
     ( <= & $value:expr) => {
         ::vate::CompareLessThanOrEqualTo(::std::borrow::Cow::Borrowed(&$value))
     };
     ( <= $value:expr) => {
-        ::vate::CompareLessThanOrEqualTo(::std::borrow::Cow::Owned($value))
+        ::vate::CompareLessThanOrEqualTo(::vate::__private::compare_owned($value))
     };
     ( > & $value:expr) => {
         ::vate::CompareGreaterThan(::std::borrow::Cow::Borrowed(&$value))
     };
     ( > $value:expr) => {
-        ::vate::CompareGreaterThan(::std::borrow::Cow::Owned($value))
+        ::vate::CompareGreaterThan(::vate::__private::compare_owned($value))
     };
     ( >= & $value:expr) => {
         ::vate::CompareGreaterThanOrEqualTo(::std::borrow::Cow::Borrowed(&$value))
     };
     ( >= $value:expr) => {
-        ::vate::CompareGreaterThanOrEqualTo(::std::borrow::Cow::Owned($value))
+        ::vate::CompareGreaterThanOrEqualTo(::vate::__private::compare_owned($value))
     };
     ( == & $value:expr) => {
         ::vate::CompareEqualTo(::std::borrow::Cow::Borrowed(&$value))
     };
     ( == $value:expr) => {
-        ::vate::CompareEqualTo(::std::borrow::Cow::Owned($value))
+        ::vate::CompareEqualTo(::vate::__private::compare_owned($value))
     };
     ( != & $value:expr) => {
         ::vate::CompareNotEqualTo(::std::borrow::Cow::Borrowed(&$value))
     };
     ( != $value:expr) => {
-        ::vate::CompareNotEqualTo(::std::borrow::Cow::Owned($value))
+        ::vate::CompareNotEqualTo(::vate::__private::compare_owned($value))
     };
 }
 
+/// Used by `Compare!` to create an owned `Cow`.
+/// Unlike `Cow::Owned`, this infers the type of the `Cow` from the value, so integer
+/// literals still infer when other crates add `PartialEq` or `PartialOrd` implementations.
+pub fn compare_owned<'a, T: Clone>(value: T) -> Cow<'a, T> {
+    Cow::Owned(value)
+}
+
 pub struct CompareLessThan<'a, T: Clone>(pub Cow<'a, T>);
 
 impl<T, D, E, U> Validator<T, D, E> for CompareLessThan<'_, U>
@@ -242,3 +249,276 @@ where
         C::apply(parent_report, child_report)
     }
 }
+
+pub struct CompareDataLessThan<D, U>(pub fn(&D) -> &U);
+
+impl<T, D, E, U> Validator<T, D, E> for CompareDataLessThan<D, U>
+where
+    T: PartialOrd<U> + Display,
+    U: Display,
+{
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(select) = self;
+        let other = select(data);
+
+        let mut child_report = Report::new(accessor);
+
+        if target.lt(other) {
+            child_report.set_valid();
+        } else {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "is \"{target}\", which is not less than \"{other}\""
+            ));
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct CompareDataLessThanOrEqualTo<D, U>(pub fn(&D) -> &U);
+
+impl<T, D, E, U> Validator<T, D, E> for CompareDataLessThanOrEqualTo<D, U>
+where
+    T: PartialOrd<U> + Display,
+    U: Display,
+{
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(select) = self;
+        let other = select(data);
+
+        let mut child_report = Report::new(accessor);
+
+        if target.le(other) {
+            child_report.set_valid();
+        } else {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "is \"{target}\", which is not less than or equal to \"{other}\""
+            ));
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct CompareDataGreaterThan<D, U>(pub fn(&D) -> &U);
+
+impl<T, D, E, U> Validator<T, D, E> for CompareDataGreaterThan<D, U>
+where
+    T: PartialOrd<U> + Display,
+    U: Display,
+{
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(select) = self;
+        let other = select(data);
+
+        let mut child_report = Report::new(accessor);
+
+        if target.gt(other) {
+            child_report.set_valid();
+        } else {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "is \"{target}\", which is not greater than \"{other}\""
+            ));
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct CompareDataGreaterThanOrEqualTo<D, U>(pub fn(&D) -> &U);
+
+impl<T, D, E, U> Validator<T, D, E> for CompareDataGreaterThanOrEqualTo<D, U>
+where
+    T: PartialOrd<U> + Display,
+    U: Display,
+{
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(select) = self;
+        let other = select(data);
+
+        let mut child_report = Report::new(accessor);
+
+        if target.ge(other) {
+            child_report.set_valid();
+        } else {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "is \"{target}\", which is not greater than or equal to \"{other}\""
+            ));
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct CompareDataEqualTo<D, U>(pub fn(&D) -> &U);
+
+impl<T, D, E, U> Validator<T, D, E> for CompareDataEqualTo<D, U>
+where
+    T: PartialEq<U> + Display,
+    U: Display,
+{
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(select) = self;
+        let other = select(data);
+
+        let mut child_report = Report::new(accessor);
+
+        if target.eq(other) {
+            child_report.set_valid();
+        } else {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "is \"{target}\", which is not equal to \"{other}\""
+            ));
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct CompareDataNotEqualTo<D, U>(pub fn(&D) -> &U);
+
+impl<T, D, E, U> Validator<T, D, E> for CompareDataNotEqualTo<D, U>
+where
+    T: PartialEq<U> + Display,
+    U: Display,
+{
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(select) = self;
+        let other = select(data);
+
+        let mut child_report = Report::new(accessor);
+
+        if target.ne(other) {
+            child_report.set_valid();
+        } else {
+            child_report.set_invalid();
+            child_report.set_message(format!("is \"{target}\", which is equal to \"{other}\""));
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vate::{
+        path, Accessor, CompareDataEqualTo, CompareDataGreaterThanOrEqualTo, CompareDataNotEqualTo,
+        Everything, Report, Validate,
+    };
+
+    struct Record {
+        email: String,
+        version: u32,
+    }
+
+    #[test]
+    fn compare_data() {
+        #[derive(Validate)]
+        #[vate(data = Record)]
+        struct Example {
+            #[vate(CompareDataNotEqualTo(|record: &Record| &record.email))]
+            email: String,
+            #[vate(CompareDataGreaterThanOrEqualTo(|record: &Record| &record.version))]
+            version: u32,
+            #[vate(CompareDataEqualTo(|record: &Record| &record.version))]
+            expected_version: u32,
+        }
+
+        let record = Record {
+            email: String::from("a@example.com"),
+            version: 3,
+        };
+
+        let example = Example {
+            email: String::from("a@example.com"),
+            version: 4,
+            expected_version: 2,
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&record, &mut report);
+
+        assert!(report.is_invalid_at_path(path!(example.email)).unwrap());
+        assert!(report.is_valid_at_path(path!(example.version)).unwrap());
+        assert!(report
+            .is_invalid_at_path(path!(example.expected_version))
+            .unwrap());
+    }
+
+    #[test]
+    fn compare_literal() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(Compare!( < 5 ))]
+            a: u8,
+            #[vate(Compare!( >= -1 ))]
+            b: i64,
+            #[vate(Compare!( != 0.5 ))]
+            c: f32,
+            #[cfg(feature = "json")]
+            #[vate(Compare!( == 5 ))]
+            d: serde_json::Value,
+        }
+
+        let example = Example {
+            a: 5,
+            b: -1,
+            c: 0.5,
+            #[cfg(feature = "json")]
+            d: serde_json::Value::from(5),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert_eq!(
+            report.get_at_path(path!(example.a)).unwrap().get_message(),
+            "is \"5\", which is not less than \"5\""
+        );
+        assert!(report.is_valid_at_path(path!(example.b)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.c)).unwrap());
+        #[cfg(feature = "json")]
+        assert!(report.is_valid_at_path(path!(example.d)).unwrap());
+    }
+}
@@ -0,0 +1,289 @@
+use std::{fmt::Display, ops::RangeInclusive, time::SystemTime};
+
+use crate::{Accessor, Collector, DataMap, Exit, Report, Validator};
+
+/// Provides the current time to temporal validators through the validation data,
+/// so validations can be tested at a fixed time.
+pub trait Clock {
+    /// Get the current time.
+    fn now(&self) -> SystemTime;
+}
+
+/// The clock of the system.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A clock stopped at a fixed time.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedClock(pub SystemTime);
+
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        self.0
+    }
+}
+
+impl Clock for () {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Uses the `FixedClock` of the map, or the clock of the system if there is none.
+impl Clock for DataMap {
+    fn now(&self) -> SystemTime {
+        self.get::<FixedClock>()
+            .map_or_else(SystemTime::now, |clock| clock.0)
+    }
+}
+
+/// A point in time, such as a date and time with a timezone.
+pub trait Timestamp {
+    /// Convert the point in time into a system time.
+    fn to_system_time(&self) -> SystemTime;
+}
+
+/// A calendar date, such as a date of birth.
+pub trait CalendarDate {
+    /// Get the number of full years from the date to the UTC date of a time,
+    /// or `None` if the date is after it.
+    fn years_until(&self, time: SystemTime) -> Option<u32>;
+}
+
+pub struct DateTimeBeforeNow;
+
+impl<T: Timestamp + Display, D: Clock, E> Validator<T, D, E> for DateTimeBeforeNow {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let mut child_report = Report::new(accessor);
+
+        if target.to_system_time() < data.now() {
+            child_report.set_valid();
+        } else {
+            child_report.set_invalid();
+            child_report.set_message(format!("is \"{target}\", which is not in the past"));
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct DateTimeAfterNow;
+
+impl<T: Timestamp + Display, D: Clock, E> Validator<T, D, E> for DateTimeAfterNow {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let mut child_report = Report::new(accessor);
+
+        if target.to_system_time() > data.now() {
+            child_report.set_valid();
+        } else {
+            child_report.set_invalid();
+            child_report.set_message(format!("is \"{target}\", which is not in the future"));
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct DateTimeWithin<B>(pub RangeInclusive<B>);
+
+impl<T, D, E, B> Validator<T, D, E> for DateTimeWithin<B>
+where
+    T: Timestamp + Display,
+    B: Timestamp + Display,
+{
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(range) = self;
+        let (start, end) = (range.start(), range.end());
+
+        let mut child_report = Report::new(accessor);
+
+        let time = target.to_system_time();
+        if start.to_system_time() <= time && time <= end.to_system_time() {
+            child_report.set_valid();
+        } else {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "is \"{target}\", which is not between \"{start}\" and \"{end}\""
+            ));
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct DateAgeAtLeast(pub u32);
+
+impl<T: CalendarDate + Display, D: Clock, E> Validator<T, D, E> for DateAgeAtLeast {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(years) = *self;
+
+        let mut child_report = Report::new(accessor);
+
+        if target
+            .years_until(data.now())
+            .is_some_and(|age| age >= years)
+        {
+            child_report.set_valid();
+        } else {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "is \"{target}\", which is less than {years} years ago"
+            ));
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: ::chrono::TimeZone> Timestamp for ::chrono::DateTime<Tz> {
+    fn to_system_time(&self) -> SystemTime {
+        SystemTime::from(self.clone())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl CalendarDate for ::chrono::NaiveDate {
+    fn years_until(&self, time: SystemTime) -> Option<u32> {
+        let today = ::chrono::DateTime::<::chrono::Utc>::from(time).date_naive();
+        today.years_since(*self)
+    }
+}
+
+#[cfg(feature = "time")]
+impl Timestamp for ::time::OffsetDateTime {
+    fn to_system_time(&self) -> SystemTime {
+        SystemTime::from(*self)
+    }
+}
+
+#[cfg(feature = "time")]
+impl CalendarDate for ::time::Date {
+    fn years_until(&self, time: SystemTime) -> Option<u32> {
+        let today = ::time::OffsetDateTime::from(time).date();
+        if today < *self {
+            return None;
+        }
+        let years = today.year() - self.year();
+        if (today.month(), today.day()) < (self.month(), self.day()) {
+            Some((years - 1) as u32)
+        } else {
+            Some(years as u32)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        use chrono::{DateTime, NaiveDate, Utc};
+        use vate::{
+            path, Accessor, DateAgeAtLeast, DateTimeAfterNow, DateTimeBeforeNow, DateTimeWithin,
+            Everything, FixedClock, Report, Validate,
+        };
+
+        #[derive(Validate)]
+        #[vate(data = FixedClock)]
+        struct Example {
+            #[vate(DateTimeBeforeNow)]
+            created_at: DateTime<Utc>,
+            #[vate(DateTimeAfterNow)]
+            expires_at: DateTime<Utc>,
+            #[vate(DateTimeWithin(
+                "2024-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+                    ..="2024-12-31T23:59:59Z".parse::<DateTime<Utc>>().unwrap()
+            ))]
+            scheduled_at: DateTime<Utc>,
+            #[vate(DateAgeAtLeast(18))]
+            born_on: NaiveDate,
+        }
+
+        let now = "2024-06-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        let example = Example {
+            created_at: "2024-05-01T00:00:00Z".parse().unwrap(),
+            expires_at: "2024-05-31T00:00:00Z".parse().unwrap(),
+            scheduled_at: "2025-01-01T00:00:00Z".parse().unwrap(),
+            born_on: NaiveDate::from_ymd_opt(2006, 6, 1).unwrap(),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&FixedClock(now.into()), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.created_at)).unwrap());
+        assert!(report
+            .is_invalid_at_path(path!(example.expires_at))
+            .unwrap());
+        assert!(report
+            .is_invalid_at_path(path!(example.scheduled_at))
+            .unwrap());
+        assert!(report.is_valid_at_path(path!(example.born_on)).unwrap());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() {
+        use time::{Date, Month, OffsetDateTime};
+        use vate::{
+            path, Accessor, DateAgeAtLeast, DateTimeBeforeNow, Everything, FixedClock, Report,
+            Validate,
+        };
+
+        #[derive(Validate)]
+        #[vate(data = FixedClock)]
+        struct Example {
+            #[vate(DateTimeBeforeNow)]
+            created_at: OffsetDateTime,
+            #[vate(DateAgeAtLeast(18))]
+            born_on: Date,
+        }
+
+        let date = |year, month, day| Date::from_calendar_date(year, month, day).unwrap();
+        let now = date(2024, Month::June, 1).midnight().assume_utc();
+
+        let example = Example {
+            created_at: date(2024, Month::July, 1).midnight().assume_utc(),
+            born_on: date(2006, Month::June, 2),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&FixedClock(now.into()), &mut report);
+
+        assert!(report
+            .is_invalid_at_path(path!(example.created_at))
+            .unwrap());
+        assert!(report.is_invalid_at_path(path!(example.born_on)).unwrap());
+    }
+}
@@ -0,0 +1,332 @@
+use std::{
+    collections::{hash_map::Entry, BTreeSet, HashMap, HashSet},
+    future::Future,
+};
+
+use crate::{validators::string::hostname_error, Accessor, Collector, Exit, Report, Validator};
+
+/// Checks if the domain of an email address can receive email, such as by looking up
+/// its MX records, falling back to its A and AAAA records.
+pub trait MailDomainResolver<E> {
+    /// Check if the domain can receive email.
+    fn accepts_mail(&self, domain: &str) -> impl Future<Output = Result<bool, E>> + Send;
+}
+
+impl<E> MailDomainResolver<E> for HashSet<String> {
+    async fn accepts_mail(&self, domain: &str) -> Result<bool, E> {
+        Ok(self.contains(domain))
+    }
+}
+
+impl<E> MailDomainResolver<E> for BTreeSet<String> {
+    async fn accepts_mail(&self, domain: &str) -> Result<bool, E> {
+        Ok(self.contains(domain))
+    }
+}
+
+/// Looks up if domains can receive email without waiting, since validators are synchronous.
+pub trait MailDomains {
+    /// Look up if the domain can receive email, or `None` if it is not known.
+    fn known_accepts_mail(&self, domain: &str) -> Option<bool>;
+}
+
+impl MailDomains for HashSet<String> {
+    fn known_accepts_mail(&self, domain: &str) -> Option<bool> {
+        Some(self.contains(domain))
+    }
+}
+
+impl MailDomains for BTreeSet<String> {
+    fn known_accepts_mail(&self, domain: &str) -> Option<bool> {
+        Some(self.contains(domain))
+    }
+}
+
+/// The domains of email addresses resolved by a `MailDomainResolver` before validation,
+/// which can be added to the data passed to validators.
+#[derive(Clone, Debug, Default)]
+pub struct ResolvedMailDomains {
+    /// Whether each resolved domain can receive email.
+    accepts_mail: HashMap<String, bool>,
+}
+
+impl ResolvedMailDomains {
+    /// Resolve the domains of the email addresses with the resolver, stopping at the first
+    /// failed lookup. Strings that are not email addresses are skipped.
+    pub async fn resolve<E, R, I>(resolver: &R, addresses: I) -> Result<Self, E>
+    where
+        R: MailDomainResolver<E>,
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut accepts_mail = HashMap::new();
+        for address in addresses {
+            let Some(domain) = mail_domain(address.as_ref()) else {
+                continue;
+            };
+            if let Entry::Vacant(entry) = accepts_mail.entry(domain) {
+                let accepts = resolver.accepts_mail(entry.key()).await?;
+                entry.insert(accepts);
+            }
+        }
+        Ok(Self { accepts_mail })
+    }
+}
+
+impl MailDomains for ResolvedMailDomains {
+    fn known_accepts_mail(&self, domain: &str) -> Option<bool> {
+        self.accepts_mail.get(domain).copied()
+    }
+}
+
+/// Get the domain of an email address in lowercase, without a trailing dot.
+fn mail_domain(address: &str) -> Option<String> {
+    match address.rsplit_once('@') {
+        Some((local, domain)) if !local.is_empty() && !domain.is_empty() => {
+            Some(domain.trim_end_matches('.').to_ascii_lowercase())
+        }
+        _ => None,
+    }
+}
+
+/// Check the syntax of an email address, returning the reason it is not valid (if any).
+/// The local part must be a dot-atom, and the domain must be a domain name with at least
+/// two labels.
+fn email_error(address: &str) -> Option<String> {
+    if address.len() > 254 {
+        return Some(String::from("it is longer than 254 bytes"));
+    }
+
+    let Some((local, domain)) = address.rsplit_once('@') else {
+        return Some(String::from("it is missing an \"@\""));
+    };
+
+    if local.is_empty() {
+        return Some(String::from("its local part is empty"));
+    }
+    if local.len() > 64 {
+        return Some(String::from("its local part is longer than 64 bytes"));
+    }
+    if !local
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~.".contains(c))
+    {
+        return Some(String::from("its local part has invalid characters"));
+    }
+    if local.starts_with('.') || local.ends_with('.') || local.contains("..") {
+        return Some(String::from("its local part has misplaced dots"));
+    }
+
+    if let Some(reason) = hostname_error(domain, false) {
+        return Some(format!("its domain {reason}"));
+    }
+    if !domain.contains('.') {
+        return Some(String::from("its domain has no top-level domain"));
+    }
+
+    None
+}
+
+pub struct StringEmail;
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringEmail {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let target = target.as_ref();
+
+        let mut child_report = Report::new(accessor);
+
+        match email_error(target) {
+            None => child_report.set_valid(),
+            Some(reason) => {
+                child_report.set_invalid();
+                child_report.set_message(format!(
+                    "is \"{target}\", which is not an email address, since {reason}"
+                ));
+            }
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct StringEmailDeliverable<'a, R>(pub &'a R);
+
+impl<T: AsRef<str>, D, E, R: MailDomains> Validator<T, D, E> for StringEmailDeliverable<'_, R> {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(domains) = self;
+        let target = target.as_ref();
+
+        let mut child_report = Report::new(accessor);
+
+        match mail_domain(target).map(|domain| domains.known_accepts_mail(&domain)) {
+            Some(Some(true)) => child_report.set_valid(),
+            Some(Some(false)) => {
+                child_report.set_invalid();
+                child_report.set_message(format!(
+                    "is \"{target}\", whose domain cannot receive email"
+                ));
+            }
+            Some(None) => {
+                child_report.set_invalid();
+                child_report.set_message(format!("is \"{target}\", whose domain was not resolved"));
+            }
+            None => {
+                child_report.set_invalid();
+                child_report.set_message(format!("is \"{target}\", which is not an email address"));
+            }
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use vate::{
+        path, Accessor, Everything, MailDomainResolver, MailDomains, Report, ResolvedMailDomains,
+        StringEmail, StringEmailDeliverable, Validate,
+    };
+
+    struct Domains {
+        mail_domains: HashSet<String>,
+    }
+
+    #[test]
+    fn string_email() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringEmail)]
+            a: String,
+            #[vate(StringEmail)]
+            b: String,
+            #[vate(StringEmail)]
+            c: String,
+            #[vate(StringEmail)]
+            d: String,
+            #[vate(StringEmail)]
+            e: String,
+            #[vate(StringEmail)]
+            f: String,
+        }
+
+        let example = Example {
+            a: String::from("first.last+tag@mail.example.com"),
+            b: String::from("example.com"),
+            c: String::from("first..last@example.com"),
+            d: String::from("user@-example.com"),
+            e: String::from("user@localhost"),
+            f: format!("{}@example.com", "é".repeat(33)),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert_eq!(
+            report.get_at_path(path!(example.b)).unwrap().get_message(),
+            "is \"example.com\", which is not an email address, since it is missing an \"@\""
+        );
+        assert!(report.is_invalid_at_path(path!(example.c)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.d)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.e)).unwrap());
+        assert!(report
+            .get_at_path(path!(example.f))
+            .unwrap()
+            .get_message()
+            .ends_with("since its local part is longer than 64 bytes"));
+    }
+
+    #[test]
+    fn string_email_deliverable() {
+        #[derive(Validate)]
+        #[vate(data = Domains)]
+        struct Example {
+            #[vate(StringEmailDeliverable(&data.mail_domains))]
+            a: String,
+            #[vate(StringEmailDeliverable(&data.mail_domains))]
+            b: String,
+            #[vate(StringEmailDeliverable(&data.mail_domains))]
+            c: String,
+        }
+
+        let example = Example {
+            a: String::from("gintoki@Yorozuya.example"),
+            b: String::from("gintoki@dead.example"),
+            c: String::from("gintoki"),
+        };
+        let data = Domains {
+            mail_domains: HashSet::from([String::from("yorozuya.example")]),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&data, &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.b)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.c)).unwrap());
+    }
+
+    #[tokio::test]
+    async fn resolved_mail_domains() {
+        #[derive(Validate)]
+        #[vate(data = ResolvedMailDomains)]
+        struct Example {
+            #[vate(StringEmailDeliverable(data))]
+            a: String,
+            #[vate(StringEmailDeliverable(data))]
+            b: String,
+            #[vate(StringEmailDeliverable(data))]
+            c: String,
+        }
+
+        let example = Example {
+            a: String::from("gintoki@Yorozuya.example."),
+            b: String::from("gintoki@dead.example"),
+            c: String::from("gintoki@unresolved.example"),
+        };
+
+        let mail_domains = HashSet::from([String::from("yorozuya.example")]);
+        let data =
+            ResolvedMailDomains::resolve::<(), _, _>(&mail_domains, [&example.a, &example.b])
+                .await
+                .unwrap();
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&data, &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.b)).unwrap());
+        assert_eq!(
+            report.get_at_path(path!(example.c)).unwrap().get_message(),
+            "is \"gintoki@unresolved.example\", whose domain was not resolved"
+        );
+    }
+
+    #[tokio::test]
+    async fn mail_domains_and_mail_domain_resolver() {
+        let mail_domains = HashSet::from([String::from("yorozuya.example")]);
+
+        assert_eq!(
+            mail_domains.known_accepts_mail("yorozuya.example"),
+            Some(true)
+        );
+        assert_eq!(
+            mail_domains.accepts_mail("dead.example").await,
+            Ok::<_, ()>(false)
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    validators::checksum::{digits, luhn},
+    Accessor, Collector, Exit, Report, Validator,
+};
+
+/// A payment card brand, recognized from the prefix and length of a card number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CardBrand {
+    AmericanExpress,
+    DinersClub,
+    Discover,
+    Jcb,
+    Mastercard,
+    UnionPay,
+    Visa,
+}
+
+impl CardBrand {
+    /// Every recognized brand.
+    pub const ALL: &'static [Self] = &[
+        Self::AmericanExpress,
+        Self::DinersClub,
+        Self::Discover,
+        Self::Jcb,
+        Self::Mastercard,
+        Self::UnionPay,
+        Self::Visa,
+    ];
+
+    /// Identify the brand of a card number of digits, if it is recognized.
+    pub fn identify(number: &str) -> Option<Self> {
+        let prefix = |len: usize| {
+            number
+                .get(..len)
+                .and_then(|prefix| prefix.parse::<u32>().ok())
+        };
+        let len = number.len();
+
+        if prefix(1) == Some(4) && matches!(len, 13 | 16 | 19) {
+            Some(Self::Visa)
+        } else if matches!(prefix(2), Some(34 | 37)) && len == 15 {
+            Some(Self::AmericanExpress)
+        } else if (matches!(prefix(2), Some(51..=55)) || matches!(prefix(4), Some(2221..=2720)))
+            && len == 16
+        {
+            Some(Self::Mastercard)
+        } else if (prefix(4) == Some(6011)
+            || matches!(prefix(3), Some(644..=649))
+            || prefix(2) == Some(65))
+            && (16..=19).contains(&len)
+        {
+            Some(Self::Discover)
+        } else if matches!(prefix(4), Some(3528..=3589)) && (16..=19).contains(&len) {
+            Some(Self::Jcb)
+        } else if (matches!(prefix(3), Some(300..=305)) || matches!(prefix(2), Some(36 | 38 | 39)))
+            && (14..=19).contains(&len)
+        {
+            Some(Self::DinersClub)
+        } else if prefix(2) == Some(62) && (16..=19).contains(&len) {
+            Some(Self::UnionPay)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for CardBrand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::AmericanExpress => write!(f, "American Express"),
+            Self::DinersClub => write!(f, "Diners Club"),
+            Self::Discover => write!(f, "Discover"),
+            Self::Jcb => write!(f, "JCB"),
+            Self::Mastercard => write!(f, "Mastercard"),
+            Self::UnionPay => write!(f, "UnionPay"),
+            Self::Visa => write!(f, "Visa"),
+        }
+    }
+}
+
+pub struct StringCreditCard<'a>(pub &'a [CardBrand]);
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringCreditCard<'_> {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(brands) = *self;
+        let number = target
+            .as_ref()
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-'))
+            .collect::<String>();
+
+        let mut child_report = Report::new(accessor);
+
+        let brand = CardBrand::identify(&number);
+        let is_luhn_valid = digits(&number).is_some_and(|digits| luhn(&digits));
+
+        match brand {
+            Some(brand) if is_luhn_valid && brands.contains(&brand) => child_report.set_valid(),
+            Some(brand) if is_luhn_valid => {
+                child_report.set_invalid();
+                child_report.set_message(format!("is a {brand} number, which is not accepted"));
+            }
+            Some(brand) => {
+                child_report.set_invalid();
+                child_report.set_message(format!("is not a valid {brand} number"));
+            }
+            None => {
+                child_report.set_invalid();
+                child_report.set_message(String::from("is not a valid card number"));
+            }
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vate::{path, Accessor, CardBrand, Everything, Report, StringCreditCard, Validate};
+
+    #[test]
+    fn string_credit_card() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringCreditCard(CardBrand::ALL))]
+            a: String,
+            #[vate(StringCreditCard(CardBrand::ALL))]
+            b: String,
+            #[vate(StringCreditCard(&[CardBrand::Visa]))]
+            c: String,
+            #[vate(StringCreditCard(CardBrand::ALL))]
+            d: String,
+        }
+
+        let example = Example {
+            a: String::from("4111 1111 1111 1111"),
+            b: String::from("4111-1111-1111-1112"),
+            c: String::from("5555555555554444"),
+            d: String::from("1234567812345670"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert_eq!(
+            report.get_at_path(path!(example.b)).unwrap().get_message(),
+            "is not a valid Visa number"
+        );
+        assert_eq!(
+            report.get_at_path(path!(example.c)).unwrap().get_message(),
+            "is a Mastercard number, which is not accepted"
+        );
+        assert!(report.is_invalid_at_path(path!(example.d)).unwrap());
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{Accessor, Collector, Exit, Report, Validator};
+
+/// A geographic point, in degrees.
+pub trait Coordinates {
+    /// Get the latitude.
+    fn latitude(&self) -> f64;
+    /// Get the longitude.
+    fn longitude(&self) -> f64;
+}
+
+/// A `(latitude, longitude)` pair.
+impl Coordinates for (f64, f64) {
+    fn latitude(&self) -> f64 {
+        self.0
+    }
+    fn longitude(&self) -> f64 {
+        self.1
+    }
+}
+
+/// A `[latitude, longitude]` pair.
+impl Coordinates for [f64; 2] {
+    fn latitude(&self) -> f64 {
+        self[0]
+    }
+    fn longitude(&self) -> f64 {
+        self[1]
+    }
+}
+
+pub struct CoordinatesWithinBoundingBox {
+    pub min_lat: f64,
+    pub max_lat: f64,
+    pub min_lon: f64,
+    pub max_lon: f64,
+}
+
+impl<T: Coordinates, D, E> Validator<T, D, E> for CoordinatesWithinBoundingBox {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self {
+            min_lat,
+            max_lat,
+            min_lon,
+            max_lon,
+        } = *self;
+        let (lat, lon) = (target.latitude(), target.longitude());
+
+        let mut child_report = Report::new(accessor);
+
+        if (min_lat..=max_lat).contains(&lat) && (min_lon..=max_lon).contains(&lon) {
+            child_report.set_valid();
+        } else {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "is \"({lat}, {lon})\", which is outside of the bounding box from \"({min_lat}, {min_lon})\" to \"({max_lat}, {max_lon})\""
+            ));
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct CoordinatesWithinPolygon<'a>(pub &'a [(f64, f64)]);
+
+impl<T: Coordinates, D, E> Validator<T, D, E> for CoordinatesWithinPolygon<'_> {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(polygon) = self;
+        let (lat, lon) = (target.latitude(), target.longitude());
+
+        let mut child_report = Report::new(accessor);
+
+        if is_within_polygon(lat, lon, polygon) {
+            child_report.set_valid();
+        } else {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "is \"({lat}, {lon})\", which is outside of the polygon"
+            ));
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+/// Check if a point is within a polygon, by counting how many of its edges a ray
+/// from the point crosses.
+fn is_within_polygon(lat: f64, lon: f64, polygon: &[(f64, f64)]) -> bool {
+    let mut is_within = false;
+    for (index, &(lat1, lon1)) in polygon.iter().enumerate() {
+        let (lat2, lon2) = polygon[(index + 1) % polygon.len()];
+        if (lat1 > lat) != (lat2 > lat) && lon < (lon2 - lon1) * (lat - lat1) / (lat2 - lat1) + lon1
+        {
+            is_within = !is_within;
+        }
+    }
+    is_within
+}
+
+#[cfg(test)]
+mod tests {
+    use vate::{
+        path, Accessor, CoordinatesWithinBoundingBox, CoordinatesWithinPolygon, Everything, Report,
+        Validate,
+    };
+
+    #[test]
+    fn coordinates() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(CoordinatesWithinBoundingBox {
+                min_lat: 35.5,
+                max_lat: 35.9,
+                min_lon: 139.5,
+                max_lon: 140.0,
+            })]
+            a: (f64, f64),
+            #[vate(CoordinatesWithinBoundingBox {
+                min_lat: 35.5,
+                max_lat: 35.9,
+                min_lon: 139.5,
+                max_lon: 140.0,
+            })]
+            b: (f64, f64),
+            #[vate(CoordinatesWithinPolygon(&[(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0)]))]
+            c: [f64; 2],
+            #[vate(CoordinatesWithinPolygon(&[(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0)]))]
+            d: [f64; 2],
+        }
+
+        let example = Example {
+            a: (35.68, 139.76),
+            b: (34.69, 135.50),
+            c: [0.5, 0.5],
+            d: [1.5, 0.5],
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.b)).unwrap());
+        assert!(report.is_valid_at_path(path!(example.c)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.d)).unwrap());
+    }
+}
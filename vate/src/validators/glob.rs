@@ -0,0 +1,106 @@
+use ::globset::{Glob, GlobMatcher};
+
+use crate::{Accessor, Collector, Exit, Report, Validator};
+
+pub struct StringGlobPattern;
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringGlobPattern {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let target = target.as_ref();
+
+        let mut child_report = Report::new(accessor);
+
+        match Glob::new(target) {
+            Ok(_) => child_report.set_valid(),
+            Err(error) => {
+                child_report.set_invalid();
+                child_report.set_message(format!(
+                    "is \"{target}\", which is not a valid glob: {}",
+                    error.kind()
+                ));
+            }
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct StringMatchesGlob<'a>(pub &'a GlobMatcher);
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringMatchesGlob<'_> {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(matcher) = self;
+        let target = target.as_ref();
+
+        let mut child_report = Report::new(accessor);
+
+        if matcher.is_match(target) {
+            child_report.set_valid();
+        } else {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "is \"{target}\", which does not match glob {}",
+                matcher.glob()
+            ));
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::OnceLock;
+
+    use vate::{
+        extras::{Glob, GlobMatcher},
+        path, Accessor, Everything, Report, StringGlobPattern, StringMatchesGlob, Validate,
+    };
+
+    fn config_glob() -> &'static GlobMatcher {
+        static CONFIG_GLOB: OnceLock<GlobMatcher> = OnceLock::new();
+        CONFIG_GLOB.get_or_init(|| Glob::new("config/**/*.toml").unwrap().compile_matcher())
+    }
+
+    #[test]
+    fn string_glob() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringGlobPattern)]
+            a: String,
+            #[vate(StringGlobPattern)]
+            b: String,
+            #[vate(StringMatchesGlob(config_glob()))]
+            c: String,
+            #[vate(StringMatchesGlob(config_glob()))]
+            d: String,
+        }
+
+        let example = Example {
+            a: String::from("src/**/*.rs"),
+            b: String::from("src/[a-"),
+            c: String::from("config/app/database.toml"),
+            d: String::from("secrets/database.toml"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.b)).unwrap());
+        assert!(report.is_valid_at_path(path!(example.c)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.d)).unwrap());
+    }
+}
@@ -0,0 +1,280 @@
+use crate::{Accessor, Collector, Exit, Report, Validator};
+
+/// The tags disallowed by `StringHtmlRestricted::Denylist`.
+const DENIED_TAGS: &[&str] = &[
+    "base", "embed", "frame", "frameset", "iframe", "link", "meta", "object", "script", "style",
+];
+
+/// The URL schemes disallowed in attribute values, since they run scripts or embed content.
+const DENIED_SCHEMES: &[&str] = &["data", "javascript", "vbscript"];
+
+/// A violation of a HTML content policy.
+enum Violation {
+    Tag(String),
+    Attribute(String),
+}
+
+/// Scan the tags of HTML, returning the first violation of the policy. Tags outside of
+/// `allowed` are violations, or tags in `DENIED_TAGS` if there is no allowlist. Event handler
+/// attributes and URLs with a scheme in `DENIED_SCHEMES` are always violations.
+fn find_violation(html: &str, allowed: Option<&[&str]>) -> Option<Violation> {
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+
+        let tag = rest.strip_prefix('/').unwrap_or(rest);
+        let name_len = tag
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '-')
+            .unwrap_or(tag.len());
+        if name_len == 0 || !tag.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            // Not a tag, such as a comment or a stray `<`.
+            continue;
+        }
+
+        let name = tag[..name_len].to_ascii_lowercase();
+        let is_allowed = match allowed {
+            Some(allowed) => allowed
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(&name)),
+            None => !DENIED_TAGS.contains(&name.as_str()),
+        };
+        if !is_allowed {
+            return Some(Violation::Tag(name));
+        }
+
+        rest = &tag[name_len..];
+        loop {
+            rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+            if rest.is_empty() || rest.starts_with('>') {
+                break;
+            }
+
+            let attribute_len = rest
+                .find(|c: char| c.is_ascii_whitespace() || matches!(c, '/' | '>' | '='))
+                .unwrap_or(rest.len())
+                .max(1);
+            let attribute = rest[..attribute_len].to_ascii_lowercase();
+            rest = rest[attribute_len..].trim_start();
+
+            let mut value = "";
+            if let Some(after_equals) = rest.strip_prefix('=') {
+                rest = after_equals.trim_start();
+                let (value_len, skip) = match rest.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let len = rest[1..].find(quote).map_or(rest.len() - 1, |len| len);
+                        rest = &rest[1..];
+                        (len, 1)
+                    }
+                    _ => (
+                        rest.find(|c: char| c.is_ascii_whitespace() || c == '>')
+                            .unwrap_or(rest.len()),
+                        0,
+                    ),
+                };
+                value = &rest[..value_len];
+                rest = &rest[(value_len + skip).min(rest.len())..];
+            }
+
+            let is_denied_url = normalize_url(value)
+                .split_once(':')
+                .is_some_and(|(scheme, _)| {
+                    DENIED_SCHEMES
+                        .iter()
+                        .any(|denied| scheme.eq_ignore_ascii_case(denied))
+                });
+            if attribute.starts_with("on") || is_denied_url {
+                return Some(Violation::Attribute(attribute));
+            }
+        }
+    }
+
+    None
+}
+
+/// Decode the character references of an attribute value, and remove ASCII whitespace and
+/// control characters, which browsers ignore in URLs.
+fn normalize_url(value: &str) -> String {
+    let mut normalized = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(c) = rest.chars().next() {
+        let (decoded, len) = match c {
+            '&' => decode_reference(rest).unwrap_or(('&', 1)),
+            _ => (c, c.len_utf8()),
+        };
+        rest = &rest[len..];
+        if !decoded.is_ascii_whitespace() && !decoded.is_ascii_control() {
+            normalized.push(decoded);
+        }
+    }
+
+    normalized
+}
+
+/// Decode the character reference at the start of the text, returning the character and the
+/// length of the reference. Numeric references may omit the semicolon, like in browsers, and
+/// only the named references that can hide a URL scheme are decoded.
+fn decode_reference(text: &str) -> Option<(char, usize)> {
+    let body = text.strip_prefix('&')?;
+
+    if let Some(number) = body.strip_prefix('#') {
+        let (digits, radix, prefix_len) = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => (hex, 16, 2),
+            None => (number, 10, 1),
+        };
+        let digits_len = digits
+            .find(|c: char| !c.is_digit(radix))
+            .unwrap_or(digits.len());
+        let code = u32::from_str_radix(&digits[..digits_len], radix).ok()?;
+        let decoded = char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER);
+        let semicolon_len = usize::from(digits[digits_len..].starts_with(';'));
+        return Some((decoded, 1 + prefix_len + digits_len + semicolon_len));
+    }
+
+    let name_len = body.find(';')?;
+    let decoded = match &body[..name_len] {
+        "amp" => '&',
+        "colon" => ':',
+        "NewLine" => '\n',
+        "Tab" => '\t',
+        _ => return None,
+    };
+    Some((decoded, name_len + 2))
+}
+
+pub enum StringHtmlRestricted<'a> {
+    /// Disallows tags that load or run content, such as `script`, `style`, and `iframe`.
+    Denylist,
+    /// Disallows tags that are not in the specified set.
+    Allowlist(&'a [&'a str]),
+}
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringHtmlRestricted<'_> {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let target = target.as_ref();
+
+        let allowed = match self {
+            Self::Denylist => None,
+            Self::Allowlist(allowed) => Some(*allowed),
+        };
+
+        let mut child_report = Report::new(accessor);
+
+        match find_violation(target, allowed) {
+            None => child_report.set_valid(),
+            Some(Violation::Tag(tag)) => {
+                child_report.set_invalid();
+                child_report.set_message(format!(
+                    "is \"{target}\", which contains the disallowed tag \"{tag}\""
+                ));
+            }
+            Some(Violation::Attribute(attribute)) => {
+                child_report.set_invalid();
+                child_report.set_message(format!(
+                    "is \"{target}\", which contains the disallowed attribute \"{attribute}\""
+                ));
+            }
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vate::{path, Accessor, Everything, Report, StringHtmlRestricted, Validate};
+
+    #[test]
+    fn string_html_restricted() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringHtmlRestricted::Denylist)]
+            a: String,
+            #[vate(StringHtmlRestricted::Denylist)]
+            b: String,
+            #[vate(StringHtmlRestricted::Denylist)]
+            c: String,
+            #[vate(StringHtmlRestricted::Denylist)]
+            d: String,
+            #[vate(StringHtmlRestricted::Allowlist(&["p", "em", "strong"]))]
+            e: String,
+            #[vate(StringHtmlRestricted::Allowlist(&["p", "em", "strong"]))]
+            f: String,
+        }
+
+        let example = Example {
+            a: String::from("<p>1 < 2, <em>really</em></p><!-- note -->"),
+            b: String::from("<p>hi</p><SCRIPT>alert(1)</SCRIPT>"),
+            c: String::from(r#"<img src="a.png" onerror="alert(1)">"#),
+            d: String::from(r#"<a href=" JavaScript:alert(1)">link</a>"#),
+            e: String::from("<p><strong>bold</strong></p>"),
+            f: String::from(r#"<p><a href="https://example.com">link</a></p>"#),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.b)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.c)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.d)).unwrap());
+        assert!(report.is_valid_at_path(path!(example.e)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.f)).unwrap());
+    }
+
+    #[test]
+    fn string_html_restricted_urls() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringHtmlRestricted::Denylist)]
+            a: String,
+            #[vate(StringHtmlRestricted::Denylist)]
+            b: String,
+            #[vate(StringHtmlRestricted::Denylist)]
+            c: String,
+            #[vate(StringHtmlRestricted::Denylist)]
+            d: String,
+            #[vate(StringHtmlRestricted::Denylist)]
+            e: String,
+            #[vate(StringHtmlRestricted::Denylist)]
+            f: String,
+            #[vate(StringHtmlRestricted::Denylist)]
+            g: String,
+            #[vate(StringHtmlRestricted::Denylist)]
+            h: String,
+        }
+
+        let example = Example {
+            a: String::from(r#"<a href="https://example.com/?a=1&amp;b=2">link</a>"#),
+            b: String::from(r#"<a href="jav&#x61;script:alert(1)">link</a>"#),
+            c: String::from(r#"<a href="&#106avascript:alert(1)">link</a>"#),
+            d: String::from(r#"<a href="javascript&colon;alert(1)">link</a>"#),
+            e: String::from("<a href=\"java\tscript:alert(1)\">link</a>"),
+            f: String::from(r#"<a href="java&NewLine;script:alert(1)">link</a>"#),
+            g: String::from(r#"<a href="VBScript:msgbox(1)">link</a>"#),
+            h: String::from(r#"<img src="data:text/html;base64,PHNjcmlwdD4=">"#),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert_eq!(
+            report.get_at_path(path!(example.b)).unwrap().get_message(),
+            "is \"<a href=\"jav&#x61;script:alert(1)\">link</a>\", which contains the disallowed attribute \"href\""
+        );
+        assert!(report.is_invalid_at_path(path!(example.c)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.d)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.e)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.f)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.g)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.h)).unwrap());
+    }
+}
@@ -0,0 +1,246 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use imagesize::{Compression, ImageType};
+
+use crate::{Accessor, Collector, Exit, Report, Validator};
+
+/// An image format recognized from the header of an image.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    Avif,
+    Bmp,
+    Gif,
+    Heif,
+    Ico,
+    Jpeg,
+    Png,
+    Tiff,
+    Webp,
+}
+
+impl ImageFormat {
+    /// Every recognized format.
+    pub const ALL: &'static [Self] = &[
+        Self::Avif,
+        Self::Bmp,
+        Self::Gif,
+        Self::Heif,
+        Self::Ico,
+        Self::Jpeg,
+        Self::Png,
+        Self::Tiff,
+        Self::Webp,
+    ];
+
+    /// Identify the format of an image from its header, if it is recognized.
+    pub fn identify(bytes: &[u8]) -> Option<Self> {
+        match imagesize::image_type(bytes).ok()? {
+            ImageType::Heif(Compression::Av1) => Some(Self::Avif),
+            ImageType::Heif(_) => Some(Self::Heif),
+            ImageType::Bmp => Some(Self::Bmp),
+            ImageType::Gif => Some(Self::Gif),
+            ImageType::Ico => Some(Self::Ico),
+            ImageType::Jpeg => Some(Self::Jpeg),
+            ImageType::Png => Some(Self::Png),
+            ImageType::Tiff => Some(Self::Tiff),
+            ImageType::Webp => Some(Self::Webp),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ImageFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Avif => write!(f, "AVIF"),
+            Self::Bmp => write!(f, "BMP"),
+            Self::Gif => write!(f, "GIF"),
+            Self::Heif => write!(f, "HEIF"),
+            Self::Ico => write!(f, "ICO"),
+            Self::Jpeg => write!(f, "JPEG"),
+            Self::Png => write!(f, "PNG"),
+            Self::Tiff => write!(f, "TIFF"),
+            Self::Webp => write!(f, "WebP"),
+        }
+    }
+}
+
+/// Read the width and height of an image from its header, without decoding it.
+fn dimensions(bytes: &[u8]) -> Option<(usize, usize)> {
+    ImageFormat::identify(bytes)?;
+    let size = imagesize::blob_size(bytes).ok()?;
+    Some((size.width, size.height))
+}
+
+pub struct BytesImageFormatIn<'a>(pub &'a [ImageFormat]);
+
+impl<T: AsRef<[u8]>, D, E> Validator<T, D, E> for BytesImageFormatIn<'_> {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(formats) = *self;
+
+        let mut child_report = Report::new(accessor);
+
+        match ImageFormat::identify(target.as_ref()) {
+            Some(format) if formats.contains(&format) => child_report.set_valid(),
+            Some(format) => {
+                child_report.set_invalid();
+                child_report.set_message(format!("is a {format} image, which is not allowed"));
+            }
+            None => {
+                child_report.set_invalid();
+                child_report.set_message(String::from("is not a recognized image"));
+            }
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct BytesImageDimensionsAtMost {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<T: AsRef<[u8]>, D, E> Validator<T, D, E> for BytesImageDimensionsAtMost {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self {
+            width: max_width,
+            height: max_height,
+        } = *self;
+
+        let mut child_report = Report::new(accessor);
+
+        match dimensions(target.as_ref()) {
+            Some((width, height)) if width <= max_width && height <= max_height => {
+                child_report.set_valid();
+            }
+            Some((width, height)) => {
+                child_report.set_invalid();
+                child_report.set_message(format!(
+                    "is a {width}x{height} image, which is larger than {max_width}x{max_height}"
+                ));
+            }
+            None => {
+                child_report.set_invalid();
+                child_report.set_message(String::from("is not a recognized image"));
+            }
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct BytesImagePixelsAtMost(pub u64);
+
+impl<T: AsRef<[u8]>, D, E> Validator<T, D, E> for BytesImagePixelsAtMost {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(max_pixels) = *self;
+
+        let mut child_report = Report::new(accessor);
+
+        match dimensions(target.as_ref()) {
+            Some((width, height)) => {
+                let pixels = width as u64 * height as u64;
+                if pixels <= max_pixels {
+                    child_report.set_valid();
+                } else {
+                    child_report.set_invalid();
+                    child_report.set_message(format!(
+                        "is an image of {pixels} pixels, which is more than {max_pixels}"
+                    ));
+                }
+            }
+            None => {
+                child_report.set_invalid();
+                child_report.set_message(String::from("is not a recognized image"));
+            }
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vate::{
+        path, Accessor, BytesImageDimensionsAtMost, BytesImageFormatIn, BytesImagePixelsAtMost,
+        Everything, ImageFormat, Report, Validate,
+    };
+
+    /// Create the header of a PNG image.
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        bytes.extend(width.to_be_bytes());
+        bytes.extend(height.to_be_bytes());
+        bytes.extend([8, 6, 0, 0, 0]);
+        bytes
+    }
+
+    /// Create the header of a GIF image.
+    fn gif(width: u16, height: u16) -> Vec<u8> {
+        let mut bytes = b"GIF89a".to_vec();
+        bytes.extend(width.to_le_bytes());
+        bytes.extend(height.to_le_bytes());
+        bytes.extend([0, 0, 0]);
+        bytes
+    }
+
+    #[test]
+    fn bytes_image() {
+        const AVATAR_FORMATS: BytesImageFormatIn =
+            BytesImageFormatIn(&[ImageFormat::Png, ImageFormat::Jpeg]);
+
+        #[derive(Validate)]
+        struct Example {
+            #[vate(
+                AVATAR_FORMATS,
+                BytesImageDimensionsAtMost { width: 512, height: 512 },
+                BytesImagePixelsAtMost(200_000)
+            )]
+            a: Vec<u8>,
+            #[vate(AVATAR_FORMATS)]
+            b: Vec<u8>,
+            #[vate(AVATAR_FORMATS)]
+            c: Vec<u8>,
+            #[vate(BytesImageDimensionsAtMost { width: 512, height: 512 })]
+            d: Vec<u8>,
+            #[vate(BytesImagePixelsAtMost(200_000))]
+            e: Vec<u8>,
+        }
+
+        let example = Example {
+            a: png(256, 256),
+            b: gif(256, 256),
+            c: b"not an image".to_vec(),
+            d: png(1024, 16),
+            e: png(100_000, 100_000),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.b)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.c)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.d)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.e)).unwrap());
+    }
+}
@@ -22,17 +22,60 @@ where
 
         let mut child_report = Report::new(accessor);
 
-        let child_result = target
-            .clone()
-            .enumerate()
-            .try_for_each(|(index, target_element)| {
+        let child_result = {
+            #[cfg(feature = "tracing")]
+            let _span = crate::trace::iterate_span(child_report.get_accessor()).entered();
+            target
+                .clone()
+                .enumerate()
+                .try_for_each(|(index, target_element)| {
+                    validator.run::<C>(
+                        Accessor::Index(index),
+                        &target_element,
+                        data,
+                        &mut child_report,
+                    )
+                })
+        };
+
+        let parent_result = C::apply(parent_report, child_report);
+
+        child_result?;
+        parent_result
+    }
+}
+
+pub struct IteratorKeyed<V>(pub V);
+
+impl<'a, T, D, E, Key: 'a, Value: 'a, V> Validator<T, D, E> for IteratorKeyed<V>
+where
+    Key: ToString,
+    T: Iterator<Item = (&'a Key, &'a Value)> + Clone,
+    V: Validator<Value, D, E>,
+{
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(validator) = self;
+
+        let mut child_report = Report::new(accessor);
+
+        let child_result = {
+            #[cfg(feature = "tracing")]
+            let _span = crate::trace::iterate_span(child_report.get_accessor()).entered();
+            target.clone().try_for_each(|(key, value)| {
                 validator.run::<C>(
-                    Accessor::Index(index),
-                    &target_element,
+                    Accessor::Key(key.to_string()),
+                    value,
                     data,
                     &mut child_report,
                 )
-            });
+            })
+        };
 
         let parent_result = C::apply(parent_report, child_report);
 
@@ -41,11 +84,11 @@ where
     }
 }
 
-pub struct IteratorKeyed<V>(pub V);
+pub struct IteratorIntegerKeyed<V>(pub V);
 
-impl<'a, T, D, E, Key: 'a, Value: 'a, V> Validator<T, D, E> for IteratorKeyed<V>
+impl<'a, T, D, E, Key: 'a, Value: 'a, V> Validator<T, D, E> for IteratorIntegerKeyed<V>
 where
-    Key: ToString,
+    Key: Copy + Into<i128>,
     T: Iterator<Item = (&'a Key, &'a Value)> + Clone,
     V: Validator<Value, D, E>,
 {
@@ -60,14 +103,55 @@ where
 
         let mut child_report = Report::new(accessor);
 
-        let child_result = target.clone().try_for_each(|(key, value)| {
-            validator.run::<C>(
-                Accessor::Key(key.to_string()),
-                value,
-                data,
-                &mut child_report,
-            )
-        });
+        let child_result = {
+            #[cfg(feature = "tracing")]
+            let _span = crate::trace::iterate_span(child_report.get_accessor()).entered();
+            target.clone().try_for_each(|(key, value)| {
+                validator.run::<C>(
+                    Accessor::IntegerKey((*key).into()),
+                    value,
+                    data,
+                    &mut child_report,
+                )
+            })
+        };
+
+        let parent_result = C::apply(parent_report, child_report);
+
+        child_result?;
+        parent_result
+    }
+}
+
+pub struct IteratorKeysValues<VK, VV>(pub VK, pub VV);
+
+impl<'a, T, D, E, Key: 'a, Value: 'a, VK, VV> Validator<T, D, E> for IteratorKeysValues<VK, VV>
+where
+    Key: ToString,
+    T: Iterator<Item = (&'a Key, &'a Value)> + Clone,
+    VK: Validator<Key, D, E>,
+    VV: Validator<Value, D, E>,
+{
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(key_validator, value_validator) = self;
+
+        let mut child_report = Report::new(accessor);
+
+        let child_result = {
+            #[cfg(feature = "tracing")]
+            let _span = crate::trace::iterate_span(child_report.get_accessor()).entered();
+            target.clone().try_for_each(|(key, value)| {
+                let accessor = Accessor::Key(key.to_string());
+                key_validator.run::<C>(accessor.clone(), key, data, &mut child_report)?;
+                value_validator.run::<C>(accessor, value, data, &mut child_report)
+            })
+        };
 
         let parent_result = C::apply(parent_report, child_report);
 
@@ -140,7 +224,8 @@ mod tests {
 
     use vate::{
         path, Accessor, CollectionIterate, Compare, Everything, ExactSizeIteratorLengthEquals,
-        IteratorIndexed, IteratorKeyed, IteratorLengthEquals, Report, Validate,
+        IteratorIndexed, IteratorIntegerKeyed, IteratorKeyed, IteratorKeysValues,
+        IteratorLengthEquals, Report, StringAlphabetic, StringLengthRange, Validate,
     };
 
     #[test]
@@ -187,6 +272,90 @@ mod tests {
         assert!(report.is_valid_at_path(path!(example.hm["e"])).unwrap());
     }
 
+    #[test]
+    fn iterator_integer_keyed() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(CollectionIterate(IteratorIntegerKeyed(StringAlphabetic)))]
+            hm: HashMap<i64, String>,
+        }
+
+        let example = Example {
+            hm: HashMap::from([(-1, String::from("a")), (7, String::from("0"))]),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.hm[-1i64])).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.hm[7i64])).unwrap());
+        assert_eq!(
+            report
+                .get_at_path(path!(example.hm[7i64]))
+                .unwrap()
+                .get_accessor(),
+            &Accessor::IntegerKey(7)
+        );
+    }
+
+    #[test]
+    fn iterator_keys_values() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(CollectionIterate(IteratorKeysValues(StringAlphabetic, Compare!( != 2 ))))]
+            hm: HashMap<String, u32>,
+        }
+
+        let example = Example {
+            hm: HashMap::from([
+                (String::from("a"), 0),
+                (String::from("b0"), 1),
+                (String::from("c"), 2),
+            ]),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.hm["a"])).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.hm["b0"])).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.hm["c"])).unwrap());
+    }
+
+    #[test]
+    fn each() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(each(StringAlphabetic, StringLengthRange::Chars { min: 1, max: 3 }))]
+            v: Vec<String>,
+            #[vate(each_value(Compare!( >= 1 ), Compare!( <= 10 )))]
+            hm: HashMap<&'static str, u32>,
+            #[vate(CollectionIterate(IteratorLengthEquals(2)), each(each(Compare!( != 2 ))))]
+            vv: Vec<Vec<u32>>,
+        }
+
+        let example = Example {
+            v: vec![
+                String::from("abc"),
+                String::from("a0"),
+                String::from("abcd"),
+            ],
+            hm: HashMap::from([("a", 1), ("b", 11)]),
+            vv: vec![vec![0, 1], vec![2]],
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.v[0])).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.v[1])).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.v[2])).unwrap());
+        assert!(report.is_valid_at_path(path!(example.hm["a"])).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.hm["b"])).unwrap());
+        assert!(report.is_valid_at_path(path!(example.vv[0][1])).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.vv[1][0])).unwrap());
+    }
+
     #[test]
     fn iterator_length_equals() {
         #[derive(Validate)]
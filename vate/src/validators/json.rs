@@ -0,0 +1,101 @@
+use ::serde_json::Value;
+
+use crate::{Accessor, Collector, Exit, Report, Validator};
+
+pub enum StringJsonText {
+    /// Allows any JSON value.
+    Any,
+    /// Allows only JSON objects.
+    Object,
+    /// Allows only JSON arrays.
+    Array,
+}
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringJsonText {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let mut child_report = Report::new(accessor);
+
+        match serde_json::from_str::<Value>(target.as_ref()) {
+            Ok(value) => {
+                let kind = match value {
+                    Value::Null => "null",
+                    Value::Bool(_) => "a boolean",
+                    Value::Number(_) => "a number",
+                    Value::String(_) => "a string",
+                    Value::Array(_) => "an array",
+                    Value::Object(_) => "an object",
+                };
+                let expected = match self {
+                    Self::Any => None,
+                    Self::Object => Some("an object"),
+                    Self::Array => Some("an array"),
+                };
+                match expected {
+                    Some(expected) if expected != kind => {
+                        child_report.set_invalid();
+                        child_report
+                            .set_message(format!("is JSON with {kind} instead of {expected}"));
+                    }
+                    _ => child_report.set_valid(),
+                }
+            }
+            Err(error) => {
+                child_report.set_invalid();
+                child_report.set_message(format!(
+                    "is not valid JSON at line {}, column {}",
+                    error.line(),
+                    error.column()
+                ));
+            }
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vate::{path, Accessor, Everything, Report, StringJsonText, Validate};
+
+    #[test]
+    fn string_json_text() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringJsonText::Any)]
+            a: String,
+            #[vate(StringJsonText::Object)]
+            b: String,
+            #[vate(StringJsonText::Object)]
+            c: String,
+            #[vate(StringJsonText::Array)]
+            d: String,
+        }
+
+        let example = Example {
+            a: String::from("42"),
+            b: String::from(r#"{"name": "vate"}"#),
+            c: String::from("[1, 2]"),
+            d: String::from("[1,\n 2,]"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_valid_at_path(path!(example.b)).unwrap());
+        assert_eq!(
+            report.get_at_path(path!(example.c)).unwrap().get_message(),
+            "is JSON with an array instead of an object"
+        );
+        assert_eq!(
+            report.get_at_path(path!(example.d)).unwrap().get_message(),
+            "is not valid JSON at line 2, column 4"
+        );
+    }
+}
@@ -1,8 +1,32 @@
 pub(crate) mod boolean;
+pub(crate) mod budget;
 pub(crate) mod bundle;
+pub(crate) mod checksum;
 pub(crate) mod collection;
 pub(crate) mod compare;
+pub(crate) mod datetime;
+pub(crate) mod email;
+pub(crate) mod finance;
+pub(crate) mod geo;
+#[cfg(feature = "globset")]
+pub(crate) mod glob;
+pub(crate) mod html;
+#[cfg(feature = "image")]
+pub(crate) mod image;
 pub(crate) mod iterator;
+#[cfg(feature = "json")]
+pub(crate) mod json;
+#[cfg(feature = "jwt")]
+pub(crate) mod jwt;
 pub(crate) mod nested;
 pub(crate) mod option;
+pub(crate) mod password;
+#[cfg(feature = "prost")]
+pub(crate) mod prost;
+pub(crate) mod severity;
 pub(crate) mod string;
+pub(crate) mod uniqueness;
+#[cfg(feature = "url")]
+pub(crate) mod url;
+#[cfg(feature = "xml")]
+pub(crate) mod xml;
@@ -1,11 +1,15 @@
 use crate::{
-    core::{Accessor, Collector, Exit, Report, Validator},
+    core::{Accessor, Collector, Exit, FromData, Report, Validator},
     Validate,
 };
 
 pub struct Nested;
 
-impl<T: Validate<Data = D, Error = E>, D, E> Validator<T, D, E> for Nested {
+impl<T, D, E> Validator<T, D, E> for Nested
+where
+    T: Validate<Error = E>,
+    T::Data: FromData<D>,
+{
     fn run<C: Collector<E>>(
         &self,
         accessor: Accessor,
@@ -14,7 +18,32 @@ impl<T: Validate<Data = D, Error = E>, D, E> Validator<T, D, E> for Nested {
         parent_report: &mut Report<E>,
     ) -> Result<(), Exit<E>> {
         let mut child_report = Report::new(accessor);
-        let child_result = target.validate::<C>(data, &mut child_report);
+        let child_result = target.validate::<C>(FromData::from_data(data), &mut child_report);
+        let parent_result = C::apply(parent_report, child_report);
+        child_result?;
+        parent_result
+    }
+}
+
+pub struct NestedAt<'a>(pub &'a [&'a [Accessor]]);
+
+impl<T, D, E> Validator<T, D, E> for NestedAt<'_>
+where
+    T: Validate<Error = E>,
+    T::Data: FromData<D>,
+{
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(paths) = *self;
+
+        let mut child_report = Report::new(accessor);
+        let child_result =
+            target.validate_at::<C>(paths, FromData::from_data(data), &mut child_report);
         let parent_result = C::apply(parent_report, child_report);
         child_result?;
         parent_result
@@ -23,7 +52,9 @@ impl<T: Validate<Data = D, Error = E>, D, E> Validator<T, D, E> for Nested {
 
 #[cfg(test)]
 mod tests {
-    use vate::{path, Accessor, Everything, Nested, Report, StringAlphabetic, Validate};
+    use vate::{
+        path, Accessor, Compare, Everything, FromData, Nested, Report, StringAlphabetic, Validate,
+    };
 
     #[test]
     fn nested() {
@@ -52,4 +83,49 @@ mod tests {
             .is_invalid_at_path(path!(example1.example2.a))
             .unwrap());
     }
+
+    #[test]
+    fn nested_from_data() {
+        struct AppData {
+            limits: Limits,
+        }
+
+        struct Limits {
+            max_quantity: u32,
+        }
+
+        impl FromData<AppData> for Limits {
+            fn from_data(data: &AppData) -> &Self {
+                &data.limits
+            }
+        }
+
+        #[derive(Validate)]
+        #[vate(data = AppData)]
+        struct Order {
+            #[vate(Nested)]
+            item: Item,
+        }
+
+        #[derive(Validate)]
+        #[vate(data = Limits)]
+        struct Item {
+            #[vate(Compare!( <= data.max_quantity ))]
+            quantity: u32,
+        }
+
+        let order = Order {
+            item: Item { quantity: 11 },
+        };
+        let data = AppData {
+            limits: Limits { max_quantity: 10 },
+        };
+
+        let mut report = Report::new(Accessor::Root("order"));
+        let _ = order.validate::<Everything>(&data, &mut report);
+
+        assert!(report
+            .is_invalid_at_path(path!(order.item.quantity))
+            .unwrap());
+    }
 }
@@ -0,0 +1,149 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::OnceLock,
+};
+
+use regex::Regex;
+
+use crate::{Accessor, Collector, Exit, Report, Validator};
+
+/// A password hashing algorithm with a recognized string format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PasswordHashAlgorithm {
+    Argon2i,
+    Argon2d,
+    Argon2id,
+    Bcrypt,
+    Scrypt,
+}
+
+impl PasswordHashAlgorithm {
+    /// Every recognized algorithm.
+    pub const ALL: &'static [Self] = &[
+        Self::Argon2i,
+        Self::Argon2d,
+        Self::Argon2id,
+        Self::Bcrypt,
+        Self::Scrypt,
+    ];
+
+    /// Identify the algorithm of a password hash, if it is well-formed.
+    /// Argon2 and scrypt hashes are PHC strings, and bcrypt hashes are modular crypt strings.
+    pub fn identify(hash: &str) -> Option<Self> {
+        static ARGON2_REGEX: OnceLock<Regex> = OnceLock::new();
+        static SCRYPT_REGEX: OnceLock<Regex> = OnceLock::new();
+        static BCRYPT_REGEX: OnceLock<Regex> = OnceLock::new();
+
+        let argon2_regex = ARGON2_REGEX.get_or_init(|| {
+            Regex::new(r"^\$(argon2i|argon2d|argon2id)\$v=\d+\$m=\d+,t=\d+,p=\d+\$[A-Za-z0-9+/]+\$[A-Za-z0-9+/]+$").unwrap()
+        });
+        let scrypt_regex = SCRYPT_REGEX.get_or_init(|| {
+            Regex::new(r"^\$scrypt\$ln=\d+,r=\d+,p=\d+\$[A-Za-z0-9+/]+\$[A-Za-z0-9+/]+$").unwrap()
+        });
+        let bcrypt_regex = BCRYPT_REGEX.get_or_init(|| {
+            Regex::new(r"^\$2[aby]\$(0[4-9]|[12][0-9]|3[01])\$[./A-Za-z0-9]{53}$").unwrap()
+        });
+
+        if let Some(captures) = argon2_regex.captures(hash) {
+            match &captures[1] {
+                "argon2i" => Some(Self::Argon2i),
+                "argon2d" => Some(Self::Argon2d),
+                _ => Some(Self::Argon2id),
+            }
+        } else if scrypt_regex.is_match(hash) {
+            Some(Self::Scrypt)
+        } else if bcrypt_regex.is_match(hash) {
+            Some(Self::Bcrypt)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for PasswordHashAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Argon2i => write!(f, "argon2i"),
+            Self::Argon2d => write!(f, "argon2d"),
+            Self::Argon2id => write!(f, "argon2id"),
+            Self::Bcrypt => write!(f, "bcrypt"),
+            Self::Scrypt => write!(f, "scrypt"),
+        }
+    }
+}
+
+pub struct StringPasswordHashFormat<'a>(pub &'a [PasswordHashAlgorithm]);
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringPasswordHashFormat<'_> {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(algorithms) = self;
+
+        let mut child_report = Report::new(accessor);
+
+        match PasswordHashAlgorithm::identify(target.as_ref()) {
+            Some(algorithm) if algorithms.contains(&algorithm) => child_report.set_valid(),
+            Some(algorithm) => {
+                let algorithms = algorithms
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>();
+                child_report.set_invalid();
+                child_report.set_message(format!(
+                    "is a {algorithm} password hash instead of one of {algorithms:?}"
+                ));
+            }
+            None => {
+                child_report.set_invalid();
+                child_report.set_message("is not a password hash in a recognized format");
+            }
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vate::{
+        path, Accessor, Everything, PasswordHashAlgorithm, Report, StringPasswordHashFormat,
+        Validate,
+    };
+
+    #[test]
+    fn string_password_hash_format() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringPasswordHashFormat(PasswordHashAlgorithm::ALL))]
+            a: String,
+            #[vate(StringPasswordHashFormat(PasswordHashAlgorithm::ALL))]
+            b: String,
+            #[vate(StringPasswordHashFormat(&[PasswordHashAlgorithm::Argon2id]))]
+            c: String,
+            #[vate(StringPasswordHashFormat(PasswordHashAlgorithm::ALL))]
+            d: String,
+        }
+
+        let example = Example {
+            a: String::from(
+                "$argon2id$v=19$m=65536,t=3,p=4$c29tZXNhbHQ$RdescudvJCsgt3ub+b+dWRWJTmaaJObG",
+            ),
+            b: String::from("$2b$12$R9h/cIPz0gi.URNNX3kh2OPST9/PgBkqquzi.Ss7KIUgO2t0jWMUW"),
+            c: String::from("$2b$12$R9h/cIPz0gi.URNNX3kh2OPST9/PgBkqquzi.Ss7KIUgO2t0jWMUW"),
+            d: String::from("5f4dcc3b5aa765d61d8327deb882cf99"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_valid_at_path(path!(example.b)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.c)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.d)).unwrap());
+    }
+}
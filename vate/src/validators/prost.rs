@@ -0,0 +1,179 @@
+use std::marker::PhantomData;
+
+use ::prost::Message;
+
+use crate::{Accessor, Collector, Exit, Report, Validator};
+
+pub struct BytesMessage<M, V>(pub V, pub PhantomData<M>);
+
+impl<M, V> BytesMessage<M, V> {
+    /// Create a validator that decodes bytes as a protobuf message, passing the decoded
+    /// message to the inner validator.
+    pub const fn new(validator: V) -> Self {
+        Self(validator, PhantomData)
+    }
+}
+
+impl<T, D, E, M, V> Validator<T, D, E> for BytesMessage<M, V>
+where
+    T: AsRef<[u8]>,
+    M: Message + Default,
+    V: Validator<M, D, E>,
+{
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(validator, _) = self;
+
+        match M::decode(target.as_ref()) {
+            Ok(message) => validator.run::<C>(accessor, &message, data, parent_report),
+            Err(error) => {
+                let mut child_report = Report::new(accessor);
+                child_report.set_invalid();
+                child_report.set_message(format!(
+                    "is {} bytes, which could not be decoded: {error}",
+                    target.as_ref().len()
+                ));
+                C::apply(parent_report, child_report)
+            }
+        }
+    }
+}
+
+pub struct EnumDefinedOnly<N>(pub PhantomData<N>);
+
+impl<N> EnumDefinedOnly<N> {
+    /// Create a validator that checks if an `i32` field is a defined value of the enum.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<N> Default for EnumDefinedOnly<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D, E, N: TryFrom<i32>> Validator<i32, D, E> for EnumDefinedOnly<N> {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &i32,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let mut child_report = Report::new(accessor);
+
+        if N::try_from(*target).is_ok() {
+            child_report.set_valid();
+        } else {
+            child_report.set_invalid();
+            child_report.set_message(format!("is {target}, which is not a defined enum value"));
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+    use vate::{
+        path, Accessor, BytesMessage, EnumDefinedOnly, Everything, Nested, OptionSomeThen, Report,
+        StringLengthRange, Validate,
+    };
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+    #[repr(i32)]
+    enum Role {
+        Member = 0,
+        Admin = 1,
+    }
+
+    #[derive(Clone, PartialEq, Message, Validate)]
+    struct Profile {
+        #[prost(string, tag = "1")]
+        #[vate(StringLengthRange::Chars { min: 1, max: 16 })]
+        name: String,
+    }
+
+    #[derive(Clone, PartialEq, Message, Validate)]
+    struct CreateUser {
+        #[prost(enumeration = "Role", tag = "1")]
+        #[vate(EnumDefinedOnly::<Role>::new())]
+        role: i32,
+        #[prost(message, optional, tag = "2")]
+        #[vate(OptionSomeThen(Nested))]
+        profile: Option<Profile>,
+    }
+
+    #[test]
+    fn prost_message() {
+        let create_user = CreateUser {
+            role: 7,
+            profile: Some(Profile {
+                name: String::new(),
+            }),
+        };
+
+        let mut report = Report::new(Accessor::Root("create_user"));
+        let _ = create_user.validate::<Everything>(&(), &mut report);
+
+        assert_eq!(
+            report
+                .get_at_path(path!(create_user.role))
+                .unwrap()
+                .get_message(),
+            "is 7, which is not a defined enum value"
+        );
+        assert!(report
+            .is_invalid_at_path(path!(create_user.profile.name))
+            .unwrap());
+    }
+
+    #[test]
+    fn bytes_message() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(BytesMessage::<CreateUser, _>::new(Nested))]
+            a: Vec<u8>,
+            #[vate(BytesMessage::<CreateUser, _>::new(Nested))]
+            b: Vec<u8>,
+            #[vate(BytesMessage::<CreateUser, _>::new(Nested))]
+            c: Vec<u8>,
+        }
+
+        let valid = CreateUser {
+            role: Role::Admin as i32,
+            profile: Some(Profile {
+                name: String::from("gintoki"),
+            }),
+        };
+        let invalid = CreateUser {
+            role: 7,
+            profile: None,
+        };
+
+        let example = Example {
+            a: valid.encode_to_vec(),
+            b: invalid.encode_to_vec(),
+            c: vec![0x0a, 0x05],
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.b.role)).unwrap());
+        assert!(report
+            .get_at_path(path!(example.c))
+            .unwrap()
+            .get_message()
+            .starts_with("is 2 bytes, which could not be decoded: "));
+    }
+}
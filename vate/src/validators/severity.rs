@@ -0,0 +1,180 @@
+use crate::{Accessor, Collector, Exit, Report, Validator};
+
+/// The severity of a message. Messages of invalid reports are errors unless they are
+/// prefixed, such as by `WithSeverity`, with `warning: ` or `info: `. Erroneous reports are
+/// always errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl Severity {
+    /// Get the message prefix of the severity, which is empty for errors.
+    pub const fn prefix(self) -> &'static str {
+        match self {
+            Self::Error => "",
+            Self::Warning => "warning: ",
+            Self::Info => "info: ",
+        }
+    }
+    /// Split a message into its severity and the message without the prefix.
+    pub fn parse(message: &str) -> (Self, &str) {
+        for severity in [Self::Warning, Self::Info] {
+            if let Some(message) = message.strip_prefix(severity.prefix()) {
+                return (severity, message);
+            }
+        }
+        (Self::Error, message)
+    }
+}
+
+pub struct WithSeverity<V>(pub Severity, pub V);
+
+impl<T, D, E, V: Validator<T, D, E>> Validator<T, D, E> for WithSeverity<V> {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(severity, validator) = self;
+
+        if *severity == Severity::Error {
+            return validator.run::<C>(accessor, target, data, parent_report);
+        }
+
+        let mut scratch_report = Report::new(parent_report.get_accessor().clone());
+        let result = validator.run::<C>(accessor.clone(), target, data, &mut scratch_report);
+
+        let parent_result = match scratch_report.take_child(&accessor) {
+            Some(mut child_report) => {
+                prefix_messages(&mut child_report, severity.prefix());
+                C::collect(parent_report, child_report)
+            }
+            None => Ok(()),
+        };
+
+        result?;
+        parent_result
+    }
+}
+
+/// Prefix the messages of a report and its invalid descendants. Messages that were joined by
+/// merging are prefixed separately.
+fn prefix_messages<E>(report: &mut Report<E>, prefix: &str) {
+    if report.is_invalid() && !report.get_message().is_empty() {
+        let message = report
+            .get_message()
+            .split("; ")
+            .map(|message| format!("{prefix}{message}"))
+            .collect::<Vec<_>>()
+            .join("; ");
+        report.set_message(message);
+    }
+
+    let accessors = report
+        .children()
+        .map(|child| child.get_accessor().clone())
+        .collect::<Vec<_>>();
+    for accessor in accessors {
+        if let Some(mut child_report) = report.take_child(&accessor) {
+            prefix_messages(&mut child_report, prefix);
+            report.push_child(child_report);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vate::{
+        path, Accessor, Everything, Nested, Report, Severity, SeverityReport, StringAlphabetic,
+        StringLengthRange, Validate, WithSeverity,
+    };
+
+    #[test]
+    fn with_severity() {
+        #[derive(Validate)]
+        struct Example1 {
+            #[vate(StringAlphabetic)]
+            a: String,
+            #[vate(WithSeverity(Severity::Warning, StringAlphabetic))]
+            b: String,
+            #[vate(
+                StringAlphabetic,
+                WithSeverity(Severity::Info, StringLengthRange::Chars { min: 4, max: 8 }),
+            )]
+            c: String,
+            #[vate(WithSeverity(Severity::Warning, Nested))]
+            example2: Example2,
+        }
+
+        #[derive(Validate)]
+        struct Example2 {
+            #[vate(StringAlphabetic)]
+            d: String,
+            #[vate(WithSeverity(Severity::Error, StringAlphabetic))]
+            e: String,
+        }
+
+        let example1 = Example1 {
+            a: String::from("0"),
+            b: String::from("1"),
+            c: String::from("2"),
+            example2: Example2 {
+                d: String::from("a"),
+                e: String::from("3"),
+            },
+        };
+
+        let mut report = Report::new(Accessor::Root("example1"));
+        let _ = example1.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example1.example2.d)).unwrap());
+        assert!(report
+            .is_invalid_at_path(path!(example1.example2.e))
+            .unwrap());
+        assert_eq!(
+            report
+                .get_at_path(path!(example1.example2.e))
+                .unwrap()
+                .get_message(),
+            "warning: contains non-alphabetic characters"
+        );
+
+        let severity_report = SeverityReport::from(&report);
+
+        assert!(severity_report.has_errors());
+        assert!(severity_report.has_warnings());
+        assert_eq!(
+            severity_report.errors().get("a").unwrap(),
+            ["contains non-alphabetic characters"]
+        );
+        assert_eq!(
+            severity_report.errors().get("c").unwrap(),
+            ["contains non-alphabetic characters"]
+        );
+        assert_eq!(
+            severity_report.warnings().get("b").unwrap(),
+            ["contains non-alphabetic characters"]
+        );
+        assert_eq!(
+            severity_report.warnings().get("example2.e").unwrap(),
+            ["contains non-alphabetic characters"]
+        );
+        assert_eq!(
+            severity_report.infos().get("c").unwrap(),
+            ["is not between 4 and 8 characters long"]
+        );
+        assert_eq!(severity_report.errors().iter().count(), 2);
+        assert_eq!(severity_report.warnings().iter().count(), 2);
+        assert_eq!(severity_report.infos().iter().count(), 1);
+    }
+}
@@ -1,3 +1,8 @@
+#[cfg(feature = "unicode-width")]
+use unicode_width::UnicodeWidthStr;
+
+use std::{fmt::Display, marker::PhantomData, ops::RangeInclusive, str::FromStr};
+
 use crate::extras::Regex;
 use crate::{Accessor, Collector, Exit, Report, Validator};
 
@@ -70,9 +75,61 @@ impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringAscii {
     }
 }
 
+pub struct StringNotBlank;
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringNotBlank {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let mut child_report = Report::new(accessor);
+
+        if target.as_ref().trim().is_empty() {
+            child_report.set_invalid();
+            child_report.set_message("is blank");
+        } else {
+            child_report.set_valid();
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct StringTrimmed;
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringTrimmed {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let target = target.as_ref();
+
+        let mut child_report = Report::new(accessor);
+
+        if target.trim() == target {
+            child_report.set_valid();
+        } else {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "is \"{target}\", which has leading or trailing whitespace"
+            ));
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
 pub enum StringLengthEquals {
     Bytes(usize),
     Chars(usize),
+    #[cfg(feature = "unicode-width")]
+    Width(usize),
 }
 
 impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringLengthEquals {
@@ -87,6 +144,8 @@ impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringLengthEquals {
         let (unit, required_len, target_len) = match *self {
             Self::Bytes(required_len) => ("byte", required_len, s.len()),
             Self::Chars(required_len) => ("character", required_len, s.chars().count()),
+            #[cfg(feature = "unicode-width")]
+            Self::Width(required_len) => ("column", required_len, s.width()),
         };
 
         let mut child_report = Report::new(accessor);
@@ -103,8 +162,19 @@ impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringLengthEquals {
 }
 
 pub enum StringLengthRange {
-    Bytes { min: usize, max: usize },
-    Chars { min: usize, max: usize },
+    Bytes {
+        min: usize,
+        max: usize,
+    },
+    Chars {
+        min: usize,
+        max: usize,
+    },
+    #[cfg(feature = "unicode-width")]
+    Width {
+        min: usize,
+        max: usize,
+    },
 }
 
 impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringLengthRange {
@@ -119,6 +189,8 @@ impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringLengthRange {
         let (unit, min, max, target_len) = match *self {
             Self::Bytes { min, max } => ("byte", min, max, s.len()),
             Self::Chars { min, max } => ("character", min, max, s.chars().count()),
+            #[cfg(feature = "unicode-width")]
+            Self::Width { min, max } => ("column", min, max, s.width()),
         };
 
         let mut child_report = Report::new(accessor);
@@ -161,3 +233,669 @@ impl<'a, T: AsRef<str>, D, E> Validator<T, D, E> for StringMatchesRegex<'a> {
         C::apply(parent_report, child_report)
     }
 }
+
+pub struct StringIdentifier<'a> {
+    pub max_len: usize,
+    pub reserved: &'a [&'a str],
+}
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringIdentifier<'_> {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self { max_len, reserved } = *self;
+        let target = target.as_ref();
+
+        let mut child_report = Report::new(accessor);
+
+        let is_identifier = target
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && target
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+        if !is_identifier {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "is \"{target}\", which is not an identifier of letters, digits, and underscores"
+            ));
+        } else if target.len() > max_len {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "is \"{target}\", which is longer than {max_len} characters"
+            ));
+        } else if reserved
+            .iter()
+            .any(|word| word.eq_ignore_ascii_case(target))
+        {
+            child_report.set_invalid();
+            child_report.set_message(format!("is \"{target}\", which is a reserved word"));
+        } else {
+            child_report.set_valid();
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct StringParsesAsIntegerWithin(pub RangeInclusive<i64>);
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringParsesAsIntegerWithin {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(range) = self;
+        run_parses_within::<C, E, i64>(
+            accessor,
+            target.as_ref(),
+            "an integer",
+            range,
+            parent_report,
+        )
+    }
+}
+
+pub struct StringParsesAsFloatWithin(pub RangeInclusive<f64>);
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringParsesAsFloatWithin {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(range) = self;
+        run_parses_within::<C, E, f64>(accessor, target.as_ref(), "a number", range, parent_report)
+    }
+}
+
+pub struct StringParsedAs<N, V>(pub V, pub PhantomData<N>);
+
+impl<N, V> StringParsedAs<N, V> {
+    /// Create a validator that parses a string, passing the parsed value to the inner validator.
+    pub const fn new(validator: V) -> Self {
+        Self(validator, PhantomData)
+    }
+}
+
+impl<T, D, E, N, V> Validator<T, D, E> for StringParsedAs<N, V>
+where
+    T: AsRef<str>,
+    N: FromStr,
+    N::Err: Display,
+    V: Validator<N, D, E>,
+{
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(validator, _) = self;
+        let target = target.as_ref();
+
+        match target.parse::<N>() {
+            Ok(parsed) => validator.run::<C>(accessor, &parsed, data, parent_report),
+            Err(error) => {
+                let mut child_report = Report::new(accessor);
+                child_report.set_invalid();
+                child_report.set_message(format!(
+                    "is \"{target}\", which could not be parsed: {error}"
+                ));
+                C::apply(parent_report, child_report)
+            }
+        }
+    }
+}
+
+/// Parse a string as a number and check if it is within a range.
+fn run_parses_within<C: Collector<E>, E, N: FromStr + PartialOrd + Display>(
+    accessor: Accessor,
+    target: &str,
+    kind: &str,
+    range: &RangeInclusive<N>,
+    parent_report: &mut Report<E>,
+) -> Result<(), Exit<E>> {
+    let mut child_report = Report::new(accessor);
+
+    match target.parse::<N>() {
+        Ok(number) if range.contains(&number) => child_report.set_valid(),
+        Ok(_) => {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "is \"{target}\", which is not between {} and {}",
+                range.start(),
+                range.end()
+            ));
+        }
+        Err(_) => {
+            child_report.set_invalid();
+            child_report.set_message(format!("is \"{target}\", which is not {kind}"));
+        }
+    }
+
+    C::apply(parent_report, child_report)
+}
+
+/// Check the syntax of a hostname by the rules of RFC 1123, returning the reason it is not
+/// valid (if any). Labels may also contain underscores if allowed, such as in SRV records.
+pub(crate) fn hostname_error(hostname: &str, allow_underscores: bool) -> Option<&'static str> {
+    if hostname.is_empty() {
+        return Some("is empty");
+    }
+    if hostname.len() > 253 {
+        return Some("is longer than 253 characters");
+    }
+    for label in hostname.split('.') {
+        if label.is_empty() {
+            return Some("has an empty label");
+        }
+        if label.len() > 63 {
+            return Some("has a label longer than 63 characters");
+        }
+        if !label
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || (allow_underscores && c == '_'))
+        {
+            return Some("has a label with invalid characters");
+        }
+        if label.starts_with('-') || label.ends_with('-') {
+            return Some("has a label that starts or ends with a hyphen");
+        }
+    }
+    None
+}
+
+pub struct StringHostname {
+    pub allow_trailing_dot: bool,
+    pub allow_underscores: bool,
+}
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringHostname {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self {
+            allow_trailing_dot,
+            allow_underscores,
+        } = *self;
+        let target = target.as_ref();
+
+        let mut child_report = Report::new(accessor);
+
+        let hostname = match target.strip_suffix('.') {
+            Some(hostname) if allow_trailing_dot => hostname,
+            _ => target,
+        };
+
+        match hostname_error(hostname, allow_underscores) {
+            None => child_report.set_valid(),
+            Some(reason) => {
+                child_report.set_invalid();
+                child_report.set_message(format!(
+                    "is \"{target}\", which is not a hostname, since it {reason}"
+                ));
+            }
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub enum StringBase64 {
+    Standard,
+    StandardNoPad,
+    UrlSafe,
+    UrlSafeNoPad,
+}
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringBase64 {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let target = target.as_ref();
+        let (alphabet, symbols, padded) = match self {
+            Self::Standard => ("standard", "+/", true),
+            Self::StandardNoPad => ("standard", "+/", false),
+            Self::UrlSafe => ("URL-safe", "-_", true),
+            Self::UrlSafeNoPad => ("URL-safe", "-_", false),
+        };
+
+        let mut child_report = Report::new(accessor);
+
+        let data = if padded {
+            target.trim_end_matches('=')
+        } else {
+            target
+        };
+        let padding = target.len() - data.len();
+
+        let invalid_offset = data
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_alphanumeric() && !symbols.contains(*c))
+            .map(|(offset, _)| offset);
+
+        if let Some(offset) = invalid_offset {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "is \"{target}\", which has a character that is not {alphabet} base64 at byte {offset}"
+            ));
+        } else if (padded && (target.len() % 4 != 0 || padding > 2))
+            || (!padded && data.len() % 4 == 1)
+        {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "is \"{target}\", which is not correctly padded base64"
+            ));
+        } else {
+            child_report.set_valid();
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct StringHexadecimal;
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringHexadecimal {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let target = target.as_ref();
+
+        let mut child_report = Report::new(accessor);
+
+        if target.chars().all(|c| c.is_ascii_hexdigit()) {
+            child_report.set_valid();
+        } else {
+            child_report.set_invalid();
+            child_report.set_message(format!(
+                "is \"{target}\", which contains non-hexadecimal characters"
+            ));
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct StringHexadecimalLength<V>(pub V);
+
+impl<T, D, E, V> Validator<T, D, E> for StringHexadecimalLength<V>
+where
+    T: AsRef<str>,
+    V: Validator<usize, D, E>,
+{
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(validator) = self;
+        let target = target.as_ref();
+
+        if target.chars().all(|c| c.is_ascii_hexdigit()) {
+            validator.run::<C>(accessor, &target.len(), data, parent_report)
+        } else {
+            StringHexadecimal.run::<C>(accessor, &target, data, parent_report)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vate::{
+        path, regex, Accessor, Compare, Everything, Report, StringBase64, StringHexadecimal,
+        StringHexadecimalLength, StringHostname, StringIdentifier, StringMatchesRegex,
+        StringNotBlank, StringParsedAs, StringParsesAsFloatWithin, StringParsesAsIntegerWithin,
+        StringTrimmed, Validate,
+    };
+
+    #[test]
+    fn string_matches_regex_macro() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringMatchesRegex(regex!("^[ACGT]+$")))]
+            a: String,
+            #[vate(StringMatchesRegex(regex!("^[ACGT]+$")))]
+            b: String,
+        }
+
+        let example = Example {
+            a: String::from("GATTACA"),
+            b: String::from("GATTACA!"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.b)).unwrap());
+    }
+
+    #[test]
+    fn string_identifier() {
+        const IDENTIFIER: StringIdentifier = StringIdentifier {
+            max_len: 8,
+            reserved: &["select", "table"],
+        };
+
+        #[derive(Validate)]
+        struct Example {
+            #[vate(IDENTIFIER)]
+            a: String,
+            #[vate(IDENTIFIER)]
+            b: String,
+            #[vate(IDENTIFIER)]
+            c: String,
+            #[vate(IDENTIFIER)]
+            d: String,
+            #[vate(IDENTIFIER)]
+            e: String,
+        }
+
+        let example = Example {
+            a: String::from("_user_id"),
+            b: String::from("1user"),
+            c: String::from("user-id"),
+            d: String::from("user_identifier"),
+            e: String::from("Select"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.b)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.c)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.d)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.e)).unwrap());
+    }
+
+    #[test]
+    fn string_parses_as_number_within() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringParsesAsIntegerWithin(1..=100))]
+            a: String,
+            #[vate(StringParsesAsIntegerWithin(1..=100))]
+            b: String,
+            #[vate(StringParsesAsIntegerWithin(1..=100))]
+            c: String,
+            #[vate(StringParsesAsFloatWithin(0.0..=1.0))]
+            d: String,
+            #[vate(StringParsesAsFloatWithin(0.0..=1.0))]
+            e: String,
+            #[vate(StringParsesAsIntegerWithin(1..=100))]
+            f: String,
+        }
+
+        let example = Example {
+            a: String::from("42"),
+            b: String::from("420"),
+            c: String::from("4.2"),
+            d: String::from("0.5"),
+            e: String::from("NaN"),
+            f: String::from(" 42"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert_eq!(
+            report.get_at_path(path!(example.b)).unwrap().get_message(),
+            "is \"420\", which is not between 1 and 100"
+        );
+        assert_eq!(
+            report.get_at_path(path!(example.c)).unwrap().get_message(),
+            "is \"4.2\", which is not an integer"
+        );
+        assert!(report.is_valid_at_path(path!(example.d)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.e)).unwrap());
+        assert_eq!(
+            report.get_at_path(path!(example.f)).unwrap().get_message(),
+            "is \" 42\", which is not an integer"
+        );
+    }
+
+    #[test]
+    fn string_parsed_as() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringParsedAs::<u16, _>::new(Compare!( >= 1024 )))]
+            a: String,
+            #[vate(StringParsedAs::<u16, _>::new(Compare!( >= 1024 )))]
+            b: String,
+            #[vate(StringParsedAs::<u16, _>::new(Compare!( >= 1024 )))]
+            c: String,
+            #[vate(StringParsedAs::<u16, _>::new(Compare!( >= 1024 )))]
+            d: String,
+        }
+
+        let example = Example {
+            a: String::from("8080"),
+            b: String::from("80"),
+            c: String::from("65536"),
+            d: String::from(" 8080"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.b)).unwrap());
+        assert_eq!(
+            report.get_at_path(path!(example.c)).unwrap().get_message(),
+            "is \"65536\", which could not be parsed: number too large to fit in target type"
+        );
+        assert!(report.is_invalid_at_path(path!(example.d)).unwrap());
+    }
+
+    #[test]
+    fn string_hostname() {
+        const HOSTNAME: StringHostname = StringHostname {
+            allow_trailing_dot: false,
+            allow_underscores: false,
+        };
+
+        #[derive(Validate)]
+        struct Example {
+            #[vate(HOSTNAME)]
+            a: String,
+            #[vate(HOSTNAME)]
+            b: String,
+            #[vate(HOSTNAME)]
+            c: String,
+            #[vate(HOSTNAME)]
+            d: String,
+            #[vate(StringHostname { allow_trailing_dot: true, allow_underscores: true })]
+            e: String,
+        }
+
+        let example = Example {
+            a: String::from("db-1.internal.example.com"),
+            b: String::from("example.com."),
+            c: String::from("-example.com"),
+            d: String::from("_sip._tcp.example.com"),
+            e: String::from("_sip._tcp.example.com."),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.b)).unwrap());
+        assert_eq!(
+            report.get_at_path(path!(example.c)).unwrap().get_message(),
+            "is \"-example.com\", which is not a hostname, since it has a label that starts or ends with a hyphen"
+        );
+        assert!(report.is_invalid_at_path(path!(example.d)).unwrap());
+        assert!(report.is_valid_at_path(path!(example.e)).unwrap());
+    }
+
+    #[test]
+    fn string_base64() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringBase64::Standard)]
+            a: String,
+            #[vate(StringBase64::Standard)]
+            b: String,
+            #[vate(StringBase64::Standard)]
+            c: String,
+            #[vate(StringBase64::UrlSafeNoPad)]
+            d: String,
+            #[vate(StringBase64::UrlSafeNoPad)]
+            e: String,
+        }
+
+        let example = Example {
+            a: String::from("aGk/Pz8+"),
+            b: String::from("aGk=="),
+            c: String::from("aG-/Pz8+"),
+            d: String::from("aGk_Pz8-aQ"),
+            e: String::from("aGk_Pz8-aQ=="),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.b)).unwrap());
+        assert_eq!(
+            report.get_at_path(path!(example.c)).unwrap().get_message(),
+            "is \"aG-/Pz8+\", which has a character that is not standard base64 at byte 2"
+        );
+        assert!(report.is_valid_at_path(path!(example.d)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.e)).unwrap());
+    }
+
+    #[test]
+    fn string_hexadecimal() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringHexadecimal)]
+            a: String,
+            #[vate(StringHexadecimal)]
+            b: String,
+            #[vate(StringHexadecimalLength(Compare!( == 8 )))]
+            c: String,
+            #[vate(StringHexadecimalLength(Compare!( == 8 )))]
+            d: String,
+            #[vate(StringHexadecimalLength(Compare!( == 8 )))]
+            e: String,
+        }
+
+        let example = Example {
+            a: String::from("DeadBeef"),
+            b: String::from("0xdeadbeef"),
+            c: String::from("deadbeef"),
+            d: String::from("deadbee"),
+            e: String::from("deadbeeg"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.b)).unwrap());
+        assert!(report.is_valid_at_path(path!(example.c)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.d)).unwrap());
+        assert_eq!(
+            report.get_at_path(path!(example.e)).unwrap().get_message(),
+            "is \"deadbeeg\", which contains non-hexadecimal characters"
+        );
+    }
+
+    #[test]
+    fn string_not_blank_trimmed() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringNotBlank)]
+            a: String,
+            #[vate(StringNotBlank)]
+            b: String,
+            #[vate(StringNotBlank)]
+            c: String,
+            #[vate(StringTrimmed)]
+            d: String,
+            #[vate(StringTrimmed)]
+            e: String,
+        }
+
+        let example = Example {
+            a: String::from(" a "),
+            b: String::new(),
+            c: String::from(" \t\n"),
+            d: String::from("a b"),
+            e: String::from("a\n"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.b)).unwrap());
+        assert_eq!(
+            report.get_at_path(path!(example.c)).unwrap().get_message(),
+            "is blank"
+        );
+        assert!(report.is_valid_at_path(path!(example.d)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.e)).unwrap());
+    }
+
+    #[cfg(feature = "unicode-width")]
+    #[test]
+    fn string_length_width() {
+        use vate::{StringLengthEquals, StringLengthRange};
+
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringLengthEquals::Width(4))]
+            a: String,
+            #[vate(StringLengthRange::Width { min: 1, max: 4 })]
+            b: String,
+            #[vate(StringLengthRange::Width { min: 1, max: 4 })]
+            c: String,
+        }
+
+        let example = Example {
+            a: String::from("日本"),
+            b: String::from("abcd"),
+            c: String::from("日本語"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_valid_at_path(path!(example.b)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.c)).unwrap());
+    }
+}
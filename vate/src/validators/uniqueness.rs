@@ -0,0 +1,217 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    future::Future,
+};
+
+use crate::{Accessor, Collector, Exit, Report, Validator};
+
+/// Checks if values are taken, such as usernames in a database.
+pub trait UniquenessChecker<E> {
+    /// Check if the value is taken.
+    fn is_taken(&self, value: &str) -> impl Future<Output = Result<bool, E>> + Send;
+}
+
+impl<E> UniquenessChecker<E> for HashSet<String> {
+    async fn is_taken(&self, value: &str) -> Result<bool, E> {
+        Ok(self.contains(value))
+    }
+}
+
+impl<E> UniquenessChecker<E> for BTreeSet<String> {
+    async fn is_taken(&self, value: &str) -> Result<bool, E> {
+        Ok(self.contains(value))
+    }
+}
+
+/// Looks up if values are taken without waiting, since validators are synchronous.
+pub trait TakenValues {
+    /// Look up if the value is taken, or `None` if it is not known.
+    fn is_known_taken(&self, value: &str) -> Option<bool>;
+}
+
+impl TakenValues for HashSet<String> {
+    fn is_known_taken(&self, value: &str) -> Option<bool> {
+        Some(self.contains(value))
+    }
+}
+
+impl TakenValues for BTreeSet<String> {
+    fn is_known_taken(&self, value: &str) -> Option<bool> {
+        Some(self.contains(value))
+    }
+}
+
+/// The values checked by a `UniquenessChecker` before validation, which can be added to the
+/// data passed to validators.
+#[derive(Clone, Debug, Default)]
+pub struct CheckedValues {
+    /// Whether each checked value is taken.
+    taken: HashMap<String, bool>,
+}
+
+impl CheckedValues {
+    /// Check the values with the checker, stopping at the first failed check.
+    pub async fn check<E, U, I>(checker: &U, values: I) -> Result<Self, E>
+    where
+        U: UniquenessChecker<E>,
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut taken = HashMap::new();
+        for value in values {
+            let value = value.as_ref();
+            taken.insert(String::from(value), checker.is_taken(value).await?);
+        }
+        Ok(Self { taken })
+    }
+}
+
+impl TakenValues for CheckedValues {
+    fn is_known_taken(&self, value: &str) -> Option<bool> {
+        self.taken.get(value).copied()
+    }
+}
+
+pub struct StringUnique<'a, U>(pub &'a U);
+
+impl<T: AsRef<str>, D, E, U: TakenValues> Validator<T, D, E> for StringUnique<'_, U> {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(checker) = self;
+        let target = target.as_ref();
+
+        let mut child_report = Report::new(accessor);
+
+        match checker.is_known_taken(target) {
+            Some(false) => child_report.set_valid(),
+            Some(true) => {
+                child_report.set_invalid();
+                child_report.set_message(format!("is \"{target}\", which is already taken"));
+            }
+            None => {
+                child_report.set_invalid();
+                child_report.set_message(format!("is \"{target}\", which was not checked"));
+            }
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct StringExists<'a, U>(pub &'a U);
+
+impl<T: AsRef<str>, D, E, U: TakenValues> Validator<T, D, E> for StringExists<'_, U> {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(checker) = self;
+        let target = target.as_ref();
+
+        let mut child_report = Report::new(accessor);
+
+        match checker.is_known_taken(target) {
+            Some(true) => child_report.set_valid(),
+            Some(false) => {
+                child_report.set_invalid();
+                child_report.set_message(format!("is \"{target}\", which does not exist"));
+            }
+            None => {
+                child_report.set_invalid();
+                child_report.set_message(format!("is \"{target}\", which was not checked"));
+            }
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use vate::{
+        path, Accessor, CheckedValues, Everything, Report, StringExists, StringUnique, TakenValues,
+        UniquenessChecker, Validate,
+    };
+
+    struct Users {
+        usernames: HashSet<String>,
+    }
+
+    #[test]
+    fn unique_and_exists() {
+        #[derive(Validate)]
+        #[vate(data = Users)]
+        struct Example {
+            #[vate(StringUnique(&data.usernames))]
+            username: String,
+            #[vate(StringExists(&data.usernames))]
+            referrer: String,
+        }
+
+        let example = Example {
+            username: String::from("gintoki"),
+            referrer: String::from("shinpachi"),
+        };
+        let data = Users {
+            usernames: HashSet::from([String::from("gintoki")]),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&data, &mut report);
+
+        assert!(report.is_invalid_at_path(path!(example.username)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.referrer)).unwrap());
+    }
+
+    #[tokio::test]
+    async fn checked_values() {
+        #[derive(Validate)]
+        #[vate(data = CheckedValues)]
+        struct Example {
+            #[vate(StringUnique(data))]
+            username: String,
+            #[vate(StringExists(data))]
+            referrer: String,
+        }
+
+        let example = Example {
+            username: String::from("kagura"),
+            referrer: String::from("gintoki"),
+        };
+
+        let usernames = HashSet::from([String::from("gintoki")]);
+        let data = CheckedValues::check::<(), _, _>(&usernames, [&example.username])
+            .await
+            .unwrap();
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&data, &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.username)).unwrap());
+        assert_eq!(
+            report
+                .get_at_path(path!(example.referrer))
+                .unwrap()
+                .get_message(),
+            "is \"gintoki\", which was not checked"
+        );
+    }
+
+    #[tokio::test]
+    async fn taken_values_and_uniqueness_checker() {
+        let usernames = HashSet::from([String::from("gintoki")]);
+
+        assert_eq!(usernames.is_known_taken("gintoki"), Some(true));
+        assert_eq!(usernames.is_taken("kagura").await, Ok::<_, ()>(false));
+    }
+}
@@ -0,0 +1,155 @@
+use ::quick_xml::{events::Event, Reader};
+
+use crate::{Accessor, Collector, Exit, Report, Validator};
+
+/// Parse XML text, returning the name of its root element, or a message explaining
+/// why it is not well-formed.
+fn parse_root(target: &str) -> Result<String, String> {
+    let mut reader = Reader::from_str(target);
+
+    let mut root = None;
+    let mut depth = 0usize;
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|error| format!("is not well-formed XML: {error}"))?;
+        match &event {
+            Event::Start(element) | Event::Empty(element) if depth == 0 => {
+                if root.is_some() {
+                    return Err(String::from(
+                        "is not well-formed XML: has multiple root elements",
+                    ));
+                }
+                root = Some(String::from_utf8_lossy(element.name().as_ref()).into_owned());
+                if matches!(event, Event::Start(_)) {
+                    depth += 1;
+                }
+            }
+            Event::Start(_) => depth += 1,
+            Event::End(_) => {
+                depth = depth.checked_sub(1).ok_or_else(|| {
+                    String::from("is not well-formed XML: has an end tag without a start tag")
+                })?;
+            }
+            Event::Text(text) if depth == 0 && !text.iter().all(u8::is_ascii_whitespace) => {
+                return Err(String::from(
+                    "is not well-formed XML: has text outside of the root element",
+                ));
+            }
+            Event::CData(_) if depth == 0 => {
+                return Err(String::from(
+                    "is not well-formed XML: has text outside of the root element",
+                ));
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if depth != 0 {
+        return Err(String::from(
+            "is not well-formed XML: has unclosed elements",
+        ));
+    }
+    root.ok_or_else(|| String::from("is not well-formed XML: has no root element"))
+}
+
+pub struct StringXmlText;
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringXmlText {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let mut child_report = Report::new(accessor);
+
+        match parse_root(target.as_ref()) {
+            Ok(_) => child_report.set_valid(),
+            Err(message) => {
+                child_report.set_invalid();
+                child_report.set_message(message);
+            }
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+pub struct StringXmlRoot<'a>(pub &'a str);
+
+impl<T: AsRef<str>, D, E> Validator<T, D, E> for StringXmlRoot<'_> {
+    fn run<C: Collector<E>>(
+        &self,
+        accessor: Accessor,
+        target: &T,
+        _data: &D,
+        parent_report: &mut Report<E>,
+    ) -> Result<(), Exit<E>> {
+        let Self(expected) = *self;
+
+        let mut child_report = Report::new(accessor);
+
+        match parse_root(target.as_ref()) {
+            Ok(root) if root == expected => child_report.set_valid(),
+            Ok(root) => {
+                child_report.set_invalid();
+                child_report.set_message(format!(
+                    "is XML with the root element \"{root}\" instead of \"{expected}\""
+                ));
+            }
+            Err(message) => {
+                child_report.set_invalid();
+                child_report.set_message(message);
+            }
+        }
+
+        C::apply(parent_report, child_report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vate::{path, Accessor, Everything, Report, StringXmlRoot, StringXmlText, Validate};
+
+    #[test]
+    fn string_xml() {
+        #[derive(Validate)]
+        struct Example {
+            #[vate(StringXmlText, StringXmlRoot("invoice"))]
+            a: String,
+            #[vate(StringXmlText)]
+            b: String,
+            #[vate(StringXmlText)]
+            c: String,
+            #[vate(StringXmlRoot("invoice"))]
+            d: String,
+            #[vate(StringXmlText)]
+            e: String,
+            #[vate(StringXmlText)]
+            f: String,
+        }
+
+        let example = Example {
+            a: String::from(r#"<?xml version="1.0"?><invoice id="1"><total>5</total></invoice>"#),
+            b: String::from("<invoice><total>5</invoice>"),
+            c: String::from("<invoice/><invoice/>"),
+            d: String::from("<order/>"),
+            e: String::from("</invoice>"),
+            f: String::from("<invoice/></invoice>"),
+        };
+
+        let mut report = Report::new(Accessor::Root("example"));
+        let _ = example.validate::<Everything>(&(), &mut report);
+
+        assert!(report.is_valid_at_path(path!(example.a)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.b)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.c)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.d)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.e)).unwrap());
+        assert!(report.is_invalid_at_path(path!(example.f)).unwrap());
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use ::warp::{
+    http::{header, StatusCode},
+    reject::{Reject, Rejection},
+    reply::{self, Reply, Response},
+    Filter,
+};
+use serde::de::DeserializeOwned;
+
+use crate::{Accessor, InvalidsAndErrors, ProblemDetailsReport, Valid, Validate, ValidateExt};
+
+/// A filter that extracts a JSON request body and validates it with the given data.
+/// If the body is not valid, the request is rejected with an `InvalidRejection`, which
+/// `recover` converts into a `422 Unprocessable Content` problem details response.
+pub fn json<T>(data: T::Data) -> impl Filter<Extract = (T,), Error = Rejection> + Clone
+where
+    T: DeserializeOwned + Validate + Send,
+    T::Data: Send + Sync + 'static,
+{
+    let data = Arc::new(data);
+    ::warp::body::json::<T>().and_then(move |target| validate(target, "body", data.clone()))
+}
+
+/// A filter that extracts a query string and validates it with the given data.
+/// If the query string is not valid, the request is rejected with an `InvalidRejection`, which
+/// `recover` converts into a `422 Unprocessable Content` problem details response.
+pub fn query<T>(data: T::Data) -> impl Filter<Extract = (T,), Error = Rejection> + Clone
+where
+    T: DeserializeOwned + Validate + Send + 'static,
+    T::Data: Send + Sync + 'static,
+{
+    let data = Arc::new(data);
+    ::warp::query::<T>().and_then(move |target| validate(target, "query", data.clone()))
+}
+
+/// Validate an extracted target.
+async fn validate<T: Validate>(
+    target: T,
+    root: &'static str,
+    data: Arc<T::Data>,
+) -> Result<T, Rejection> {
+    target
+        .into_valid::<InvalidsAndErrors>(Accessor::Root(root), &data)
+        .map(Valid::into_inner)
+        .map_err(|report| {
+            ::warp::reject::custom(InvalidRejection(ProblemDetailsReport::from(&report)))
+        })
+}
+
+/// The rejection of a target that is not valid.
+#[derive(Debug)]
+pub struct InvalidRejection(pub ProblemDetailsReport);
+
+impl Reject for InvalidRejection {}
+
+impl Reply for InvalidRejection {
+    fn into_response(self) -> Response {
+        let Self(problem_details) = self;
+        let status = StatusCode::from_u16(problem_details.status)
+            .unwrap_or(StatusCode::UNPROCESSABLE_ENTITY);
+        let reply = reply::with_header(
+            reply::json(&problem_details),
+            header::CONTENT_TYPE,
+            ProblemDetailsReport::CONTENT_TYPE,
+        );
+        reply::with_status(reply, status).into_response()
+    }
+}
+
+/// Recover an `InvalidRejection` into a `422 Unprocessable Content` problem details response.
+/// Other rejections are passed through.
+pub async fn recover(rejection: Rejection) -> Result<Response, Rejection> {
+    match rejection.find::<InvalidRejection>() {
+        Some(InvalidRejection(problem_details)) => {
+            Ok(InvalidRejection(problem_details.clone()).into_response())
+        }
+        None => Err(rejection),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use vate::{warp::recover, StringAlphabetic, Validate};
+    use warp::{http::StatusCode, Filter};
+
+    #[derive(Deserialize, Validate)]
+    struct Example {
+        #[vate(StringAlphabetic)]
+        a: String,
+    }
+
+    #[tokio::test]
+    async fn json() {
+        let filter = vate::warp::json::<Example>(()).map(|example: Example| example.a);
+
+        let a = warp::test::request()
+            .body(r#"{"a":"a"}"#)
+            .filter(&filter)
+            .await
+            .unwrap();
+
+        assert_eq!(a, "a");
+    }
+
+    #[tokio::test]
+    async fn json_invalid() {
+        let filter = vate::warp::json::<Example>(())
+            .map(|example: Example| example.a)
+            .recover(recover);
+
+        let response = warp::test::request()
+            .body(r#"{"a":"0"}"#)
+            .reply(&filter)
+            .await;
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            response.headers()["content-type"],
+            "application/problem+json"
+        );
+    }
+}
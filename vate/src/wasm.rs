@@ -0,0 +1,74 @@
+use serde::{de::DeserializeOwned, Serialize};
+use serde_wasm_bindgen::Serializer;
+use wasm_bindgen::JsValue;
+
+use crate::{Accessor, Exit, InvalidsAndErrors, MapReport, Report, Validate};
+
+/// Deserialize a JS value into the target and validate it, returning its `MapReport` as a
+/// JS object of paths to messages. The object is empty if the target is valid.
+/// Generic functions can't be exported with `wasm_bindgen`, so this is intended to be called
+/// by an exported function for each validated type.
+pub fn validate<T>(value: JsValue, data: &T::Data) -> Result<JsValue, JsValue>
+where
+    T: DeserializeOwned + Validate,
+{
+    let target: T = serde_wasm_bindgen::from_value(value)?;
+    Ok(map_report(&target, data).serialize(&Serializer::json_compatible())?)
+}
+
+/// Validate the target into the `MapReport` that is returned as a JS object.
+fn map_report<T: Validate>(target: &T, data: &T::Data) -> MapReport {
+    let mut report = Report::new(Accessor::Root("value"));
+    if let Err(Exit::WithError(error)) = target.validate::<InvalidsAndErrors>(data, &mut report) {
+        report.set_error(error);
+    }
+    MapReport::from(&report)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_ser_tokens, Token};
+    use vate::{Nested, StringAlphabetic, Validate};
+
+    use super::map_report;
+
+    #[test]
+    fn wasm_map_report() {
+        #[derive(Validate)]
+        struct Example1 {
+            #[vate(Nested)]
+            example2: Example2,
+        }
+
+        #[derive(Validate)]
+        struct Example2 {
+            #[vate(StringAlphabetic)]
+            a: String,
+        }
+
+        let mut example1 = Example1 {
+            example2: Example2 {
+                a: String::from("0"),
+            },
+        };
+
+        assert_ser_tokens(
+            &map_report(&example1, &()),
+            &[
+                Token::Map { len: Some(1) },
+                Token::Str("example2.a"),
+                Token::Seq { len: Some(1) },
+                Token::Str("contains non-alphabetic characters"),
+                Token::SeqEnd,
+                Token::MapEnd,
+            ],
+        );
+
+        example1.example2.a = String::from("a");
+
+        assert_ser_tokens(
+            &map_report(&example1, &()),
+            &[Token::Map { len: Some(0) }, Token::MapEnd],
+        );
+    }
+}